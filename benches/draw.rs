@@ -1,8 +1,9 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 use embedded_graphics::{
-    image::Image,
+    image::{Image, ImageDrawableExt},
     pixelcolor::{Gray8, Rgb555, Rgb888},
     prelude::*,
+    primitives::Rectangle,
 };
 use tinytga::Tga;
 
@@ -43,6 +44,59 @@ impl<C> OriginDimensions for Framebuffer<C> {
     }
 }
 
+/// Framebuffer with optimized `fill_contiguous` and `fill_solid` implementations.
+///
+/// Only used by the right origin and `fill_runs` benchmarks, which measure the drawing code paths
+/// that use these methods. All other benchmarks use `Framebuffer` to keep them comparable with
+/// earlier results.
+struct FillFramebuffer<C>(Framebuffer<C>);
+
+impl<C: PixelColor + From<Rgb888>> FillFramebuffer<C> {
+    pub fn new() -> Self {
+        Self(Framebuffer::new())
+    }
+}
+
+impl<C: PixelColor> DrawTarget for FillFramebuffer<C> {
+    type Color = C;
+    type Error = std::convert::Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = embedded_graphics::Pixel<Self::Color>>,
+    {
+        self.0.draw_iter(pixels)
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        for (p, c) in area.points().zip(colors) {
+            self.0.pixels[p.y as usize][p.x as usize] = c;
+        }
+
+        Ok(())
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let columns = area.columns();
+        let columns = columns.start as usize..columns.end as usize;
+
+        for y in area.rows() {
+            self.0.pixels[y as usize][columns.clone()].fill(color);
+        }
+
+        Ok(())
+    }
+}
+
+impl<C> OriginDimensions for FillFramebuffer<C> {
+    fn size(&self) -> embedded_graphics::prelude::Size {
+        self.0.size()
+    }
+}
+
 macro_rules! bench {
     ($c:expr, $color_type:ty, $file:expr) => {
         $c.bench_function(concat!(stringify!($color_type), " ", $file), |b| {
@@ -83,11 +137,129 @@ macro_rules! bench {
     };
 }
 
+macro_rules! bench_right_origin {
+    ($c:expr, $color_type:ty, $file:expr) => {
+        $c.bench_function(concat!(stringify!($color_type), " ", $file), |b| {
+            let mut fb = FillFramebuffer::<$color_type>::new();
+            b.iter(|| {
+                let bmp = Tga::<$color_type>::from_slice(include_bytes!(concat!(
+                    "../tests/",
+                    $file,
+                    ".tga"
+                )))
+                .unwrap();
+                Image::new(&bmp, Point::zero()).draw(&mut fb).unwrap();
+            })
+        });
+    };
+
+    ($c:expr, $color_type:ty) => {
+        bench_right_origin!($c, $color_type, "logo_type2_24bpp_br");
+        bench_right_origin!($c, $color_type, "logo_type2_24bpp_tr");
+    };
+}
+
+macro_rules! bench_fill_runs {
+    ($c:expr, $color_type:ty, $file:expr) => {
+        $c.bench_function(
+            concat!(stringify!($color_type), " fill_runs ", $file),
+            |b| {
+                let mut fb = FillFramebuffer::<$color_type>::new();
+                b.iter(|| {
+                    let bmp = Tga::<$color_type>::from_slice(include_bytes!(concat!(
+                        "../tests/",
+                        $file,
+                        ".tga"
+                    )))
+                    .unwrap();
+                    Image::new(&bmp.fill_runs(), Point::zero())
+                        .draw(&mut fb)
+                        .unwrap();
+                })
+            },
+        );
+    };
+
+    ($c:expr, $color_type:ty) => {
+        bench_fill_runs!($c, $color_type, "logo_type9_16bpp_bl");
+        bench_fill_runs!($c, $color_type, "logo_type9_16bpp_tl");
+        bench_fill_runs!($c, $color_type, "logo_type9_24bpp_bl");
+        bench_fill_runs!($c, $color_type, "logo_type9_24bpp_tl");
+        bench_fill_runs!($c, $color_type, "logo_type10_16bpp_bl");
+        bench_fill_runs!($c, $color_type, "logo_type10_16bpp_tl");
+        bench_fill_runs!($c, $color_type, "logo_type10_24bpp_bl");
+        bench_fill_runs!($c, $color_type, "logo_type10_24bpp_tl");
+        bench_fill_runs!($c, $color_type, "logo_type11_bl");
+        bench_fill_runs!($c, $color_type, "logo_type11_tl");
+    };
+}
+
 fn draw_benchmarks(c: &mut Criterion) {
     bench!(c, Rgb888);
     bench!(c, Rgb555);
     bench!(c, Gray8);
 }
 
-criterion_group!(benches, draw_benchmarks);
+fn right_origin_benchmarks(c: &mut Criterion) {
+    bench_right_origin!(c, Rgb888);
+    bench_right_origin!(c, Rgb555);
+    bench_right_origin!(c, Gray8);
+}
+
+macro_rules! bench_color_map {
+    ($group:expr, $color_type:ty, $file:expr) => {
+        let data = include_bytes!(concat!("../tests/", $file, ".tga"));
+        let tga = Tga::<$color_type>::from_slice(data).unwrap();
+        let area = Rectangle::new(Point::new(40, 60), Size::new(160, 200));
+
+        $group.bench_function(concat!(stringify!($color_type), " draw ", $file), |b| {
+            let mut fb = Framebuffer::<$color_type>::new();
+            b.iter(|| Image::new(&tga, Point::zero()).draw(&mut fb).unwrap())
+        });
+        $group.bench_function(
+            concat!(stringify!($color_type), " sub_image ", $file),
+            |b| {
+                let mut fb = Framebuffer::<$color_type>::new();
+                b.iter(|| {
+                    Image::new(&tga.sub_image(&area), Point::zero())
+                        .draw(&mut fb)
+                        .unwrap()
+                })
+            },
+        );
+        $group.bench_function(concat!(stringify!($color_type), " pixels ", $file), |b| {
+            let mut fb = Framebuffer::<$color_type>::new();
+            b.iter(|| fb.draw_iter(tga.pixels()).unwrap())
+        });
+    };
+
+    ($group:expr, $color_type:ty) => {
+        bench_color_map!($group, $color_type, "logo_type1_24bpp_tl");
+        bench_color_map!($group, $color_type, "logo_type1_16bpp_bl");
+        bench_color_map!($group, $color_type, "logo_type9_24bpp_tl");
+        bench_color_map!($group, $color_type, "logo_type9_16bpp_bl");
+    };
+}
+
+fn color_map_benchmarks(c: &mut Criterion) {
+    // The logo images use color maps with 256 entries and 8 bit color indices.
+    let mut group = c.benchmark_group("color_map");
+    bench_color_map!(group, Rgb888);
+    bench_color_map!(group, Rgb555);
+    group.finish();
+}
+
+fn fill_runs_benchmarks(c: &mut Criterion) {
+    bench_fill_runs!(c, Rgb888);
+    bench_fill_runs!(c, Rgb555);
+    bench_fill_runs!(c, Gray8);
+}
+
+criterion_group!(
+    benches,
+    draw_benchmarks,
+    right_origin_benchmarks,
+    color_map_benchmarks,
+    fill_runs_benchmarks
+);
 criterion_main!(benches);
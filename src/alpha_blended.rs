@@ -0,0 +1,129 @@
+use embedded_graphics::{
+    image::GetPixel,
+    pixelcolor::{Gray8, Rgb555, Rgb888},
+    prelude::*,
+    primitives::Rectangle,
+};
+
+use crate::Tga;
+
+/// Alpha blended TGA image.
+///
+/// `AlphaBlended` draws a TGA image by blending each pixel with a solid background color. Fully
+/// transparent pixels are skipped and fully opaque pixels are drawn without blending. See the
+/// [`alpha_blended`] method for additional information.
+///
+/// [`alpha_blended`]: struct.Tga.html#method.alpha_blended
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct AlphaBlended<'a, C> {
+    tga: Tga<'a, C>,
+    background: C,
+}
+
+impl<'a, C> AlphaBlended<'a, C> {
+    pub(crate) fn new(tga: Tga<'a, C>, background: C) -> Self {
+        Self { tga, background }
+    }
+}
+
+impl<C> OriginDimensions for AlphaBlended<'_, C> {
+    fn size(&self) -> Size {
+        self.tga.size()
+    }
+}
+
+impl<C> ImageDrawable for AlphaBlended<'_, C>
+where
+    C: PixelColor + From<Gray8> + From<Rgb555> + From<Rgb888> + Into<Rgb888>,
+{
+    type Color = C;
+
+    fn draw<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let mut pixels = self.tga.pixels();
+
+        let pixels = core::iter::from_fn(|| pixels.next_with_alpha()).filter_map(
+            |(Pixel(position, color), alpha)| {
+                blend(color, self.background, alpha).map(|color| Pixel(position, color))
+            },
+        );
+
+        target.draw_iter(pixels)
+    }
+
+    fn draw_sub_image<D>(&self, target: &mut D, area: &Rectangle) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.draw(&mut target.translated(-area.top_left).clipped(area))
+    }
+}
+
+/// Draws a TGA image by blending it with the current content of the draw target.
+pub(crate) fn draw_onto<C, D>(
+    tga: &Tga<'_, C>,
+    target: &mut D,
+    position: Point,
+) -> Result<(), D::Error>
+where
+    C: PixelColor + From<Gray8> + From<Rgb555> + From<Rgb888> + Into<Rgb888>,
+    D: DrawTarget<Color = C> + GetPixel<Color = C>,
+{
+    let mut pixels = tga.pixels();
+
+    while let Some((Pixel(point, color), alpha)) = pixels.next_with_alpha() {
+        let point = position + point;
+
+        let color = match alpha {
+            0 => continue,
+            u8::MAX => color,
+            // Pixels outside the target are skipped, because the background color is unknown.
+            _ => match target.pixel(point) {
+                Some(background) => mix(color, background, alpha),
+                None => continue,
+            },
+        };
+
+        target.draw_iter(core::iter::once(Pixel(point, color)))?;
+    }
+
+    Ok(())
+}
+
+/// Blends a foreground color with a background color.
+///
+/// Returns `None` if the foreground color is fully transparent.
+fn blend<C>(foreground: C, background: C, alpha: u8) -> Option<C>
+where
+    C: PixelColor + From<Rgb888> + Into<Rgb888>,
+{
+    match alpha {
+        0 => None,
+        u8::MAX => Some(foreground),
+        _ => Some(mix(foreground, background, alpha)),
+    }
+}
+
+/// Mixes two colors with the given foreground alpha value.
+fn mix<C>(foreground: C, background: C, alpha: u8) -> C
+where
+    C: PixelColor + From<Rgb888> + Into<Rgb888>,
+{
+    let foreground: Rgb888 = foreground.into();
+    let background: Rgb888 = background.into();
+
+    let alpha = u16::from(alpha);
+    let mix_channel = |f: u8, b: u8| {
+        let value = u16::from(f) * alpha + u16::from(b) * (255 - alpha);
+        ((value + 127) / 255) as u8
+    };
+
+    Rgb888::new(
+        mix_channel(foreground.r(), background.r()),
+        mix_channel(foreground.g(), background.g()),
+        mix_channel(foreground.b(), background.b()),
+    )
+    .into()
+}
@@ -0,0 +1,254 @@
+use embedded_graphics::{
+    pixelcolor::{Gray8, Rgb555, Rgb888},
+    prelude::*,
+    primitives::Rectangle,
+};
+use embedded_io_async::Read;
+
+use crate::{
+    color_map::ColorMap,
+    header::{TgaHeader, HEADER_LENGTH},
+    raw_iter::RawPixel,
+    read_error::{ReadDrawError, ReadError},
+    stream_decoder::{Decode, StreamDecoder, StreamSetup},
+};
+
+/// Async streaming TGA decoder.
+///
+/// `AsyncTgaReader` is the async counterpart of [`TgaReader`] and decodes a TGA image from an
+/// [`embedded_io_async::Read`] source. Only the color map is stored in a caller provided buffer,
+/// all other data is read through a small fixed size buffer.
+///
+/// The image can be read one row at a time by using the [`read_row`] and [`read_row_colors`]
+/// methods, passed to a callback by using [`read_rows`] or drawn to a draw target by using
+/// [`draw`]. The rows are returned in the order they are stored in the file, but their positions
+/// are always relative to the top left corner of the image.
+///
+/// Decoding errors can't be recovered from and the reader shouldn't be used after an error was
+/// returned.
+///
+/// This type requires the `embedded-io-async` feature.
+///
+/// # Examples
+///
+/// ```
+/// # async fn example() -> Result<(), tinytga::ReadError<core::convert::Infallible>> {
+/// use embedded_graphics::pixelcolor::Rgb888;
+/// use tinytga::{AsyncTgaReader, ReadError};
+///
+/// let data = include_bytes!("../tests/chessboard_4px_rle.tga");
+///
+/// let mut reader = AsyncTgaReader::new(&data[..], &mut []).await?;
+///
+/// let mut row = [Rgb888::default(); 4];
+/// reader
+///     .read_rows(&mut row, |y, colors| {
+///         // Process the colors in the row at position `y`.
+///         Ok::<_, ReadError<_>>(())
+///     })
+///     .await?;
+/// # Ok(()) }
+/// ```
+///
+/// [`TgaReader`]: struct.TgaReader.html
+/// [`read_row`]: #method.read_row
+/// [`read_row_colors`]: #method.read_row_colors
+/// [`read_rows`]: #method.read_rows
+/// [`draw`]: #method.draw
+#[derive(Debug)]
+pub struct AsyncTgaReader<'b, R> {
+    /// Underlying reader.
+    reader: R,
+
+    /// Image data decoder.
+    decoder: StreamDecoder<'b>,
+}
+
+impl<'b, R> AsyncTgaReader<'b, R>
+where
+    R: Read,
+{
+    /// Creates a new reader.
+    ///
+    /// The header, image ID and color map are read immediately. The color map is stored in
+    /// `color_map_buffer`, which must be large enough to hold the color map. Images without a
+    /// color map can use an empty buffer.
+    pub async fn new(
+        mut reader: R,
+        color_map_buffer: &'b mut [u8],
+    ) -> Result<Self, ReadError<R::Error>> {
+        let mut header = [0; HEADER_LENGTH];
+        reader.read_exact(&mut header).await?;
+        let mut setup = StreamSetup::new(&header)?;
+
+        // Skip the image ID.
+        while let Some(buffer) = setup.image_id_buffer() {
+            reader.read_exact(buffer).await?;
+        }
+
+        let color_map_data = setup.color_map_buffer(color_map_buffer)?;
+        reader.read_exact(color_map_data).await?;
+
+        Ok(Self {
+            reader,
+            decoder: setup.into_decoder(color_map_data)?,
+        })
+    }
+
+    /// Returns the TGA header.
+    pub fn header(&self) -> TgaHeader {
+        self.decoder.header()
+    }
+
+    /// Returns the dimensions of this image.
+    pub fn size(&self) -> Size {
+        self.decoder.size()
+    }
+
+    /// Returns the color map.
+    ///
+    /// `None` is returned if the image contains no color map.
+    pub fn color_map(&self) -> Option<&ColorMap<'b>> {
+        self.decoder.color_map()
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Reads the next pixel.
+    ///
+    /// Returns `None` if all pixels have been read. See [`TgaReader`] for more information.
+    ///
+    /// [`TgaReader`]: struct.TgaReader.html
+    pub async fn next_pixel(&mut self) -> Option<Result<RawPixel, ReadError<R::Error>>> {
+        loop {
+            match self.decoder.next_pixel() {
+                Decode::Complete(pixel) => return pixel.map(Ok),
+                Decode::NeedData => {
+                    if let Err(error) = self.fill_buffer().await {
+                        return Some(Err(error));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Reads the next row of raw pixel values.
+    ///
+    /// The values are stored in `row` from left to right, starting at index `0`. For color mapped
+    /// images the values are the color indices. Returns the Y coordinate of the row or `None` if
+    /// all rows have been read.
+    ///
+    /// `row` must be at least as long as the image width, otherwise
+    /// [`ReadError::BufferTooSmall`] is returned.
+    ///
+    /// [`ReadError::BufferTooSmall`]: enum.ReadError.html#variant.BufferTooSmall
+    pub async fn read_row(&mut self, row: &mut [u32]) -> Result<Option<u32>, ReadError<R::Error>> {
+        self.read_row_with(row.len(), |_, x, raw| {
+            row[x] = raw;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Reads the next row of colors.
+    ///
+    /// The colors are stored in `row` from left to right, starting at index `0`. Returns the Y
+    /// coordinate of the row or `None` if all rows have been read. The alpha channel of 32 bit
+    /// colors is ignored.
+    ///
+    /// `row` must be at least as long as the image width, otherwise
+    /// [`ReadError::BufferTooSmall`] is returned. Color indices outside the range of the color
+    /// map are returned as [`ParseError::InvalidColorIndex`] errors.
+    ///
+    /// [`ReadError::BufferTooSmall`]: enum.ReadError.html#variant.BufferTooSmall
+    /// [`ParseError::InvalidColorIndex`]: enum.ParseError.html#variant.InvalidColorIndex
+    pub async fn read_row_colors<C>(
+        &mut self,
+        row: &mut [C],
+    ) -> Result<Option<u32>, ReadError<R::Error>>
+    where
+        C: PixelColor + From<Gray8> + From<Rgb555> + From<Rgb888>,
+    {
+        self.read_row_with(row.len(), |decoder, x, raw| {
+            row[x] = decoder.convert(raw)?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Reads all remaining rows and passes them to a callback.
+    ///
+    /// `row` is used as a buffer to store the colors in each row and must be at least as long as
+    /// the image width. The callback is called with the Y coordinate of the row and the colors in
+    /// the row, from left to right. Errors returned by the callback stop the decoding process and
+    /// are returned by this method.
+    pub async fn read_rows<C, E, F>(&mut self, row: &mut [C], mut f: F) -> Result<(), E>
+    where
+        C: PixelColor + From<Gray8> + From<Rgb555> + From<Rgb888>,
+        E: From<ReadError<R::Error>>,
+        F: FnMut(u32, &[C]) -> Result<(), E>,
+    {
+        let width = self.size().width as usize;
+
+        while let Some(y) = self.read_row_colors(row).await? {
+            f(y, &row[0..width])?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads all remaining rows and draws them to a draw target.
+    ///
+    /// The image is drawn with its top left corner at the origin of the draw target. `row` is used
+    /// as a buffer to store the colors in each row and must be at least as long as the image
+    /// width.
+    pub async fn draw<C, D>(
+        &mut self,
+        target: &mut D,
+        row: &mut [C],
+    ) -> Result<(), ReadDrawError<R::Error, D::Error>>
+    where
+        C: PixelColor + From<Gray8> + From<Rgb555> + From<Rgb888>,
+        D: DrawTarget<Color = C>,
+    {
+        let mut row_rect = Rectangle::new(Point::zero(), Size::new(self.size().width, 1));
+
+        self.read_rows(row, |y, colors| {
+            row_rect.top_left.y = y as i32;
+
+            target
+                .fill_contiguous(&row_rect, colors.iter().copied())
+                .map_err(ReadDrawError::DrawTarget)
+        })
+        .await
+    }
+
+    /// Reads the remaining pixels in the current row and passes them to `f`.
+    async fn read_row_with<F>(
+        &mut self,
+        length: usize,
+        mut f: F,
+    ) -> Result<Option<u32>, ReadError<R::Error>>
+    where
+        F: FnMut(&StreamDecoder<'b>, usize, u32) -> Result<(), ReadError<R::Error>>,
+    {
+        self.decoder.check_row_length(length)?;
+
+        loop {
+            match self.decoder.decode_row(&mut f)? {
+                Decode::Complete(y) => return Ok(y),
+                Decode::NeedData => self.fill_buffer().await?,
+            }
+        }
+    }
+
+    /// Reads the next chunk of data into the decoder's buffer.
+    async fn fill_buffer(&mut self) -> Result<(), ReadError<R::Error>> {
+        let result = self.reader.read(self.decoder.buffer_mut()).await;
+
+        self.decoder.filled(result)
+    }
+}
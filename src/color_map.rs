@@ -1,8 +1,4 @@
 use crate::{parse_error::ParseError, Bpp, TgaHeader};
-use embedded_graphics::{
-    iterator::raw::RawDataSlice, pixelcolor::raw::LittleEndian, prelude::PixelColor,
-};
-use nom::bytes::complete::take;
 
 /// Color map.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
@@ -18,7 +14,7 @@ pub struct ColorMap<'a> {
 }
 
 impl<'a> ColorMap<'a> {
-    pub(crate) fn parse(
+    pub(crate) const fn parse(
         input: &'a [u8],
         header: &TgaHeader,
     ) -> Result<(&'a [u8], Option<Self>), ParseError> {
@@ -26,12 +22,17 @@ impl<'a> ColorMap<'a> {
             return Ok((input, None));
         }
 
-        let entry_bpp = header.color_map_depth.ok_or(ParseError::ColorMap)?;
+        let entry_bpp = match header.color_map_depth {
+            Some(entry_bpp) => entry_bpp,
+            None => return Err(ParseError::ColorMap),
+        };
 
-        let length = usize::from(header.color_map_len) * usize::from(entry_bpp.bytes());
+        let length = header.color_map_len as usize * entry_bpp.bytes() as usize;
+        if input.len() < length {
+            return Err(ParseError::ColorMap);
+        }
 
-        let (input, color_map_data) =
-            take(length)(input).map_err(|_: nom::Err<()>| ParseError::ColorMap)?;
+        let (color_map_data, input) = input.split_at(length);
 
         Ok((
             input,
@@ -45,18 +46,31 @@ impl<'a> ColorMap<'a> {
     }
 
     /// Returns the bit depth for the entries in the color map.
-    pub fn entry_bpp(&self) -> Bpp {
+    pub const fn entry_bpp(&self) -> Bpp {
         self.entry_bpp
     }
 
-    /// Returns the raw color value for a color map entry.
-    pub fn get_raw(&self, index: usize) -> Option<u32> {
-        //TODO: use start_index and add test
-        if index >= usize::from(self.length) {
-            return None;
-        }
+    /// Returns the index of the first color map entry.
+    ///
+    /// Color indices in the image data are offset by this value, i.e. the color index
+    /// `start_index` refers to the first entry in the color map.
+    pub fn start_index(&self) -> u16 {
+        self.start_index
+    }
 
-        let start = index * usize::from(self.entry_bpp.bytes());
+    /// Returns the number of entries.
+    pub(crate) fn length(&self) -> usize {
+        usize::from(self.length)
+    }
+
+    /// Returns the raw color value for a color index.
+    ///
+    /// The `index` is translated by the [`start_index`] to get the color map entry. `None` is
+    /// returned if the index is outside the range of the color map.
+    ///
+    /// [`start_index`]: #method.start_index
+    pub fn get_raw(&self, index: usize) -> Option<u32> {
+        let start = self.entry_index(index)? * usize::from(self.entry_bpp.bytes());
 
         Some(match self.entry_bpp {
             Bpp::Bits8 => self.data[start] as u32,
@@ -76,14 +90,10 @@ impl<'a> ColorMap<'a> {
         })
     }
 
-    pub(crate) fn get<C>(&self, index: usize) -> Option<C>
-    where
-        C: PixelColor + From<C::Raw>,
-        RawDataSlice<'a, C::Raw, LittleEndian>: IntoIterator<Item = C::Raw>,
-    {
-        RawDataSlice::new(self.data)
-            .into_iter()
-            .nth(index)
-            .map(|r| C::from(r))
+    /// Translates a color index into the index of the color map entry.
+    fn entry_index(&self, index: usize) -> Option<usize> {
+        index
+            .checked_sub(usize::from(self.start_index))
+            .filter(|index| *index < usize::from(self.length))
     }
 }
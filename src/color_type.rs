@@ -0,0 +1,81 @@
+use embedded_graphics::{
+    pixelcolor::{
+        raw::{RawU16, RawU24, RawU8},
+        Gray8, Rgb555, Rgb888,
+    },
+    prelude::*,
+};
+
+use crate::{
+    header::{Bpp, DataType},
+    parse_error::ParseError,
+};
+
+/// Native color type of a TGA image.
+///
+/// The color type describes the format of the colors stored in the image data or, for color
+/// mapped images, in the color map.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum ColorType {
+    /// 8 bit grayscale.
+    Gray8,
+    /// 16 bit RGB with 5 bits per channel and an optional 1 bit alpha channel.
+    Rgb555,
+    /// 24 bit RGB with 8 bits per channel.
+    Rgb888,
+    /// 32 bit RGB with 8 bits per channel and an 8 bit alpha channel.
+    Argb8888,
+}
+
+impl ColorType {
+    /// Returns the color type for an image.
+    ///
+    /// `color_bpp` is the bit depth of the color map entries for color mapped images.
+    pub(crate) const fn new(
+        data_type: DataType,
+        color_bpp: Bpp,
+        image_data_bpp: Bpp,
+    ) -> Result<Self, ParseError> {
+        // Color indices in color mapped images can be stored as 8 or 16 bit values.
+        if matches!(data_type, DataType::ColorMapped)
+            && !matches!(image_data_bpp, Bpp::Bits8 | Bpp::Bits16)
+        {
+            return Err(ParseError::UnsupportedBpp(image_data_bpp.bits()));
+        }
+
+        Ok(match (color_bpp, data_type) {
+            (Bpp::Bits8, DataType::BlackAndWhite) => ColorType::Gray8,
+            (Bpp::Bits16, DataType::ColorMapped) => ColorType::Rgb555,
+            (Bpp::Bits16, DataType::TrueColor) => ColorType::Rgb555,
+            (Bpp::Bits24, DataType::ColorMapped) => ColorType::Rgb888,
+            (Bpp::Bits24, DataType::TrueColor) => ColorType::Rgb888,
+            (Bpp::Bits32, DataType::ColorMapped) => ColorType::Argb8888,
+            (Bpp::Bits32, DataType::TrueColor) => ColorType::Argb8888,
+            _ => return Err(ParseError::UnsupportedTgaType(data_type, color_bpp)),
+        })
+    }
+
+    /// Returns the number of bits per color.
+    pub fn bpp(self) -> Bpp {
+        match self {
+            ColorType::Gray8 => Bpp::Bits8,
+            ColorType::Rgb555 => Bpp::Bits16,
+            ColorType::Rgb888 => Bpp::Bits24,
+            ColorType::Argb8888 => Bpp::Bits32,
+        }
+    }
+
+    /// Converts a raw color value into a color.
+    pub(crate) fn convert<C>(self, raw: u32) -> C
+    where
+        C: PixelColor + From<Gray8> + From<Rgb555> + From<Rgb888>,
+    {
+        match self {
+            ColorType::Gray8 => Gray8::from(RawU8::from_u32(raw)).into(),
+            ColorType::Rgb555 => Rgb555::from(RawU16::from_u32(raw)).into(),
+            // The alpha channel in Argb8888 colors is discarded by `RawU24::from_u32`.
+            ColorType::Rgb888 | ColorType::Argb8888 => Rgb888::from(RawU24::from_u32(raw)).into(),
+        }
+    }
+}
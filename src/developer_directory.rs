@@ -0,0 +1,76 @@
+/// Length of a single developer directory entry in bytes
+pub(crate) const DEVELOPER_TAG_LENGTH: usize = 10;
+
+/// Developer directory entry.
+///
+/// Tags 0 to 32767 can be freely used by applications. Tags 32768 to 65535 are reserved.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct DeveloperTag<'a> {
+    /// Tag
+    pub tag: u16,
+
+    /// Data referenced by the tag
+    pub data: &'a [u8],
+}
+
+/// Iterator over the entries in the developer directory.
+///
+/// Entries that reference data outside the TGA file are skipped. If the directory declares more
+/// entries than it contains the iteration stops after the last complete entry.
+///
+/// See the [`developer_tags`] method for additional information.
+///
+/// [`developer_tags`]: struct.RawTga.html#method.developer_tags
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct DeveloperTags<'a> {
+    /// TGA file data.
+    data: &'a [u8],
+
+    /// Remaining directory entries.
+    entries: &'a [u8],
+}
+
+impl<'a> DeveloperTags<'a> {
+    /// Creates a new iterator.
+    ///
+    /// `data` is the entire TGA file and `directory` the developer directory, if it is present.
+    pub(crate) fn new(data: &'a [u8], directory: Option<&'a [u8]>) -> Self {
+        let entries = directory
+            .and_then(|directory| {
+                let count = usize::from(u16::from_le_bytes([
+                    *directory.first()?,
+                    *directory.get(1)?,
+                ]));
+                let end = (2 + count * DEVELOPER_TAG_LENGTH).min(directory.len());
+
+                directory.get(2..end)
+            })
+            .unwrap_or(&[]);
+
+        Self { data, entries }
+    }
+}
+
+impl<'a> Iterator for DeveloperTags<'a> {
+    type Item = DeveloperTag<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.entries.len() >= DEVELOPER_TAG_LENGTH {
+            let (entry, remaining) = self.entries.split_at(DEVELOPER_TAG_LENGTH);
+            self.entries = remaining;
+
+            let tag = u16::from_le_bytes([entry[0], entry[1]]);
+            let offset = u32::from_le_bytes([entry[2], entry[3], entry[4], entry[5]]) as usize;
+            let size = u32::from_le_bytes([entry[6], entry[7], entry[8], entry[9]]) as usize;
+
+            if let Some(data) = offset
+                .checked_add(size)
+                .and_then(|end| self.data.get(offset..end))
+            {
+                return Some(DeveloperTag { tag, data });
+            }
+        }
+
+        None
+    }
+}
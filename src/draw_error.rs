@@ -0,0 +1,14 @@
+use crate::parse_error::ParseError;
+
+/// Errors returned by [`Tga::try_draw`].
+///
+/// [`Tga::try_draw`]: struct.Tga.html#method.try_draw
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum DrawError<E> {
+    /// The image data couldn't be decoded.
+    Parse(ParseError),
+
+    /// The draw target returned an error.
+    DrawTarget(E),
+}
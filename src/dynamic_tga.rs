@@ -0,0 +1,134 @@
+use embedded_graphics::{
+    pixelcolor::{Gray8, Rgb555, Rgb888},
+    prelude::*,
+    primitives::Rectangle,
+};
+
+use crate::{color_type::ColorType, parse_error::ParseError, RawTga, Tga};
+
+/// TGA image with a color type that is determined at runtime.
+///
+/// `DynamicTga` uses the native color type of the image, which makes it possible to choose the
+/// draw target or color format after the image was parsed. Each variant contains a [`Tga`], which
+/// can be drawn or accessed like any other image.
+///
+/// Images with an alpha channel in 32 bit colors use the [`Argb8888`] variant, which contains an
+/// [`Rgb888`] image. The alpha channel can be used with [`Tga::alpha_blended`].
+///
+/// `DynamicTga` can also be drawn directly, which converts the colors of all variants to
+/// [`Rgb888`]. Draw targets with other color types can be used by wrapping them in
+/// [`DrawTargetExt::color_converted`].
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{image::Image, mock_display::MockDisplay, prelude::*};
+/// use tinytga::{ColorType, DynamicTga};
+///
+/// let data = include_bytes!("../tests/chessboard_4px_rle.tga");
+/// let tga = DynamicTga::from_slice(data).unwrap();
+/// assert_eq!(tga.color_type(), ColorType::Rgb888);
+///
+/// match tga {
+///     DynamicTga::Gray8(tga) => {
+///         // Draw to a grayscale display.
+///         # let _ = tga;
+///     }
+///     DynamicTga::Rgb888(tga) | DynamicTga::Argb8888(tga) => {
+///         let mut display = MockDisplay::new();
+///         Image::new(&tga, Point::zero()).draw(&mut display)?;
+///     }
+///     _ => {}
+/// }
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`Tga`]: struct.Tga.html
+/// [`Argb8888`]: #variant.Argb8888
+/// [`Rgb888`]: embedded_graphics::pixelcolor::Rgb888
+/// [`Tga::alpha_blended`]: struct.Tga.html#method.alpha_blended
+/// [`DrawTargetExt::color_converted`]: embedded_graphics::draw_target::DrawTargetExt::color_converted
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum DynamicTga<'a> {
+    /// 8 bit grayscale image.
+    Gray8(Tga<'a, Gray8>),
+    /// 16 bit RGB image.
+    Rgb555(Tga<'a, Rgb555>),
+    /// 24 bit RGB image.
+    Rgb888(Tga<'a, Rgb888>),
+    /// 32 bit RGB image with an alpha channel.
+    ///
+    /// embedded-graphics doesn't provide a color type with an alpha channel, which is why the
+    /// contained image uses [`Rgb888`] colors, like the [`Rgb888`](#variant.Rgb888) variant.
+    /// The separate variant makes it possible to detect images with an alpha channel by matching
+    /// on the variant, e.g. to draw them using [`Tga::alpha_blended`] instead of ignoring the
+    /// alpha channel.
+    Argb8888(Tga<'a, Rgb888>),
+}
+
+impl<'a> DynamicTga<'a> {
+    /// Parses a TGA image from a byte slice.
+    pub fn from_slice(data: &'a [u8]) -> Result<Self, ParseError> {
+        let raw = RawTga::from_slice(data)?;
+        let color_type = raw.color_type()?;
+
+        Ok(match color_type {
+            ColorType::Gray8 => Self::Gray8(Tga::from_raw(raw, color_type)),
+            ColorType::Rgb555 => Self::Rgb555(Tga::from_raw(raw, color_type)),
+            ColorType::Rgb888 => Self::Rgb888(Tga::from_raw(raw, color_type)),
+            ColorType::Argb8888 => Self::Argb8888(Tga::from_raw(raw, color_type)),
+        })
+    }
+
+    /// Returns the native color type of this image.
+    pub fn color_type(&self) -> ColorType {
+        match self {
+            Self::Gray8(_) => ColorType::Gray8,
+            Self::Rgb555(_) => ColorType::Rgb555,
+            Self::Rgb888(_) => ColorType::Rgb888,
+            Self::Argb8888(_) => ColorType::Argb8888,
+        }
+    }
+
+    /// Returns a reference to the raw TGA image.
+    pub fn as_raw(&self) -> &RawTga<'a> {
+        match self {
+            Self::Gray8(tga) => tga.as_raw(),
+            Self::Rgb555(tga) => tga.as_raw(),
+            Self::Rgb888(tga) | Self::Argb8888(tga) => tga.as_raw(),
+        }
+    }
+}
+
+impl OriginDimensions for DynamicTga<'_> {
+    fn size(&self) -> Size {
+        self.as_raw().size()
+    }
+}
+
+impl ImageDrawable for DynamicTga<'_> {
+    type Color = Rgb888;
+
+    fn draw<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        match self {
+            Self::Gray8(tga) => tga.draw(&mut target.color_converted()),
+            Self::Rgb555(tga) => tga.draw(&mut target.color_converted()),
+            Self::Rgb888(tga) | Self::Argb8888(tga) => tga.draw(target),
+        }
+    }
+
+    fn draw_sub_image<D>(&self, target: &mut D, area: &Rectangle) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        match self {
+            Self::Gray8(tga) => tga.draw_sub_image(&mut target.color_converted(), area),
+            Self::Rgb555(tga) => tga.draw_sub_image(&mut target.color_converted(), area),
+            Self::Rgb888(tga) | Self::Argb8888(tga) => tga.draw_sub_image(target, area),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+/// Possible encoder errors
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum EncodeError {
+    /// The image is too large.
+    ///
+    /// The width and height of an image can be at most `65535` and the width and height of a
+    /// postage stamp image at most `255`. This error is also returned if the file would be larger
+    /// than 4 GiB.
+    ImageTooLarge,
+
+    /// The image ID is longer than `255` bytes.
+    ImageIdTooLong,
+
+    /// A string in the extension area is too long.
+    ///
+    /// The author name, job name and software ID can be up to 40 bytes and each author comment
+    /// line can be up to 80 bytes long.
+    ExtensionFieldTooLong,
+
+    /// The developer directory contains more than `65535` entries.
+    TooManyDeveloperTags,
+
+    /// The color map contains more than `65535` entries.
+    ColorMapTooLarge,
+
+    /// The color type can't be used for color map entries.
+    ///
+    /// Color maps with 8 bit `Gray8` entries aren't supported.
+    UnsupportedColorMap,
+
+    /// A color in the image or the postage stamp isn't contained in the color map.
+    ColorNotInColorMap,
+
+    /// Mismatched number of colors.
+    ///
+    /// The number of colors passed to the encoder doesn't match the image size.
+    MismatchedLength {
+        /// Expected number of colors.
+        expected: usize,
+
+        /// Actual number of colors.
+        actual: usize,
+    },
+
+    /// The output buffer is too small to hold the encoded image.
+    BufferTooSmall {
+        /// Required buffer size in bytes.
+        required: usize,
+    },
+}
@@ -0,0 +1,717 @@
+use core::marker::PhantomData;
+use embedded_graphics::{
+    pixelcolor::{
+        raw::{RawU16, RawU24, RawU32},
+        Gray8, GrayColor, Rgb555, Rgb888,
+    },
+    prelude::*,
+};
+
+use crate::{
+    developer_directory::{DeveloperTag, DEVELOPER_TAG_LENGTH},
+    encode_error::EncodeError,
+    extension::{TgaExtension, EXTENSION_AREA_LENGTH},
+    footer::{footer_bytes, TGA_FOOTER_LENGTH},
+    header::{Bpp, Compression, DataType, ImageOrigin, TgaHeader, HEADER_LENGTH},
+};
+
+/// Color types supported by the [`TgaEncoder`].
+///
+/// [`TgaEncoder`]: struct.TgaEncoder.html
+pub trait EncoderColor: Copy + PartialEq {
+    /// Data type used to store colors of this type.
+    const DATA_TYPE: DataType;
+
+    /// Bits per pixel.
+    const BPP: Bpp;
+
+    /// Number of bits used for the alpha channel.
+    const ALPHA_CHANNEL_DEPTH: u8;
+
+    /// Converts the color into the raw value that is stored in the image data.
+    fn into_raw(self) -> u32;
+}
+
+impl EncoderColor for Gray8 {
+    const DATA_TYPE: DataType = DataType::BlackAndWhite;
+    const BPP: Bpp = Bpp::Bits8;
+    const ALPHA_CHANNEL_DEPTH: u8 = 0;
+
+    fn into_raw(self) -> u32 {
+        u32::from(self.luma())
+    }
+}
+
+impl EncoderColor for Rgb555 {
+    const DATA_TYPE: DataType = DataType::TrueColor;
+    const BPP: Bpp = Bpp::Bits16;
+    const ALPHA_CHANNEL_DEPTH: u8 = 0;
+
+    fn into_raw(self) -> u32 {
+        u32::from(RawU16::from(self).into_inner())
+    }
+}
+
+impl EncoderColor for Rgb888 {
+    const DATA_TYPE: DataType = DataType::TrueColor;
+    const BPP: Bpp = Bpp::Bits24;
+    const ALPHA_CHANNEL_DEPTH: u8 = 0;
+
+    fn into_raw(self) -> u32 {
+        RawU24::from(self).into_inner()
+    }
+}
+
+/// 32 bit colors with an 8 bit alpha channel in A:R:G:B format.
+///
+/// The raw values use the same format as the values returned by [`RawTga::pixels`] for 32 bit
+/// images.
+///
+/// [`RawTga::pixels`]: struct.RawTga.html#method.pixels
+impl EncoderColor for RawU32 {
+    const DATA_TYPE: DataType = DataType::TrueColor;
+    const BPP: Bpp = Bpp::Bits32;
+    const ALPHA_CHANNEL_DEPTH: u8 = 8;
+
+    fn into_raw(self) -> u32 {
+        self.into_inner()
+    }
+}
+
+/// TGA encoder.
+///
+/// The encoder writes true color (type 2 and 10), grayscale (type 3 and 11) or color mapped
+/// (type 1 and 9) TGA files, which can either be uncompressed or RLE compressed. The image type
+/// and bit depth are determined by the color type `C`:
+///
+/// | Color type | Image type  | Bits per pixel |
+/// |------------|-------------|----------------|
+/// | `Gray8`    | Grayscale   | 8              |
+/// | `Rgb555`   | True color  | 16             |
+/// | `Rgb888`   | True color  | 24             |
+/// | `RawU32`   | True color  | 32             |
+///
+/// Color mapped images are written if a color map is set by using [`with_color_map`]. The color
+/// map entries use the bit depth of `C` and the image data contains 8 or 16 bit color indices.
+/// `Gray8` can't be used for color mapped images, because 8 bit color map entries aren't
+/// supported.
+///
+/// The TGA 2.0 extension area, postage stamp and developer directory can optionally be added to
+/// the file by using the [`with_extension`], [`with_postage_stamp`] and [`with_developer_tags`]
+/// methods.
+///
+/// The [`encode`] method appends the encoded image to a collection, like a `Vec<u8>`. To encode
+/// images without an allocator, [`encode_into`] can be used to write the encoded image into a
+/// byte slice.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{pixelcolor::Rgb888, prelude::*};
+/// use tinytga::{Compression, ImageOrigin, Tga, TgaEncoder};
+///
+/// let colors = [Rgb888::RED, Rgb888::GREEN, Rgb888::BLUE, Rgb888::WHITE];
+///
+/// let encoder = TgaEncoder::new(Size::new(2, 2))
+///     .with_image_origin(ImageOrigin::BottomLeft)
+///     .with_compression(Compression::Rle)
+///     .with_image_id(b"example");
+///
+/// let mut data = Vec::new();
+/// encoder.encode(&colors, &mut data).unwrap();
+///
+/// let tga = Tga::<Rgb888>::from_slice(&data).unwrap();
+/// assert_eq!(tga.size(), Size::new(2, 2));
+/// ```
+///
+/// [`with_color_map`]: #method.with_color_map
+/// [`with_extension`]: #method.with_extension
+/// [`with_postage_stamp`]: #method.with_postage_stamp
+/// [`with_developer_tags`]: #method.with_developer_tags
+/// [`encode`]: #method.encode
+/// [`encode_into`]: #method.encode_into
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct TgaEncoder<'a, C> {
+    /// Image size.
+    size: Size,
+
+    /// Image origin.
+    image_origin: ImageOrigin,
+
+    /// Image ID.
+    image_id: &'a [u8],
+
+    /// Compression.
+    compression: Compression,
+
+    /// Color map.
+    color_map: Option<&'a [C]>,
+
+    /// Extension area.
+    extension: Option<TgaExtension<'a>>,
+
+    /// Postage stamp colors and size.
+    postage_stamp: Option<(&'a [C], Size)>,
+
+    /// Developer directory entries.
+    developer_tags: &'a [DeveloperTag<'a>],
+
+    color_type: PhantomData<C>,
+}
+
+impl<'a, C> TgaEncoder<'a, C>
+where
+    C: EncoderColor,
+{
+    /// Creates a new encoder.
+    ///
+    /// By default the image is stored uncompressed, with the origin in the top left corner and
+    /// without an image ID.
+    pub fn new(size: Size) -> Self {
+        Self {
+            size,
+            image_origin: ImageOrigin::TopLeft,
+            image_id: &[],
+            compression: Compression::Uncompressed,
+            color_map: None,
+            extension: None,
+            postage_stamp: None,
+            developer_tags: &[],
+            color_type: PhantomData,
+        }
+    }
+
+    /// Sets the image origin.
+    ///
+    /// The image origin only changes the order in which the pixels are stored in the file. The
+    /// colors passed to the encoder are always expected in top left origin order.
+    pub fn with_image_origin(self, image_origin: ImageOrigin) -> Self {
+        Self {
+            image_origin,
+            ..self
+        }
+    }
+
+    /// Sets the image ID.
+    ///
+    /// The image ID can be up to 255 bytes long.
+    pub fn with_image_id(self, image_id: &'a [u8]) -> Self {
+        Self { image_id, ..self }
+    }
+
+    /// Sets the compression.
+    ///
+    /// RLE compressed image data is split into packets of up to 128 pixels. Packets never cross
+    /// a row boundary.
+    pub fn with_compression(self, compression: Compression) -> Self {
+        Self {
+            compression,
+            ..self
+        }
+    }
+
+    /// Sets the color map.
+    ///
+    /// If a color map is set, the image is stored as a color mapped image. Each color in the
+    /// image and the postage stamp is replaced by the index of the first matching color map
+    /// entry, which is determined by searching the color map. The indices are stored as 8 bit
+    /// values for color maps with up to 256 entries and as 16 bit values for larger color maps.
+    ///
+    /// The color map can contain up to 65535 entries.
+    pub fn with_color_map(self, color_map: &'a [C]) -> Self {
+        Self {
+            color_map: Some(color_map),
+            ..self
+        }
+    }
+
+    /// Sets the extension area.
+    ///
+    /// The offsets in the extension area are ignored and replaced by the offsets of the sections
+    /// which are written by the encoder. A TGA 2.0 footer is added to the file if an extension
+    /// area is set.
+    pub fn with_extension(self, extension: TgaExtension<'a>) -> Self {
+        Self {
+            extension: Some(extension),
+            ..self
+        }
+    }
+
+    /// Sets the postage stamp image.
+    ///
+    /// The postage stamp is stored uncompressed, with the same image origin as the main image.
+    /// The colors must be stored in row-major order, starting at the top left corner of the image,
+    /// and the width and height can be at most 255. If no extension area was set an empty
+    /// extension area is added to the file.
+    pub fn with_postage_stamp(self, colors: &'a [C], size: Size) -> Self {
+        Self {
+            postage_stamp: Some((colors, size)),
+            ..self
+        }
+    }
+
+    /// Sets the developer directory entries.
+    ///
+    /// A TGA 2.0 footer is added to the file if at least one developer tag is set.
+    pub fn with_developer_tags(self, developer_tags: &'a [DeveloperTag<'a>]) -> Self {
+        Self {
+            developer_tags,
+            ..self
+        }
+    }
+
+    /// Encodes an image.
+    ///
+    /// The colors must be stored in row-major order, starting at the top left corner of the
+    /// image. The encoded TGA file is appended to `output`.
+    ///
+    /// An error is returned if the number of colors doesn't match the image size or if the
+    /// encoder settings are invalid. All errors, including offsets of the extension area and
+    /// developer directory which don't fit into the 32 bit fields of the footer, are detected
+    /// before anything is written to `output`.
+    pub fn encode<E>(&self, colors: &[C], output: &mut E) -> Result<(), EncodeError>
+    where
+        E: Extend<u8>,
+    {
+        let header = self.header()?;
+        self.check(colors)?;
+
+        let mut output = CountingWriter {
+            output,
+            position: 0,
+        };
+
+        output.extend(header.to_bytes());
+        output.extend(self.image_id.iter().copied());
+        for color in self.color_map.unwrap_or(&[]) {
+            output.extend(raw_bytes(color.into_raw(), C::BPP));
+        }
+        self.write_image_data(colors, self.size, self.compression, &mut output);
+
+        let mut extension_area_offset = 0;
+        if let Some(extension) = self.extension_area() {
+            extension_area_offset = file_offset(output.position);
+
+            let postage_stamp_offset = self
+                .postage_stamp
+                .map(|_| output.position + EXTENSION_AREA_LENGTH);
+
+            let extension = TgaExtension {
+                color_correction_offset: None,
+                postage_stamp_offset,
+                scan_line_offset: None,
+                ..extension
+            };
+            // The extension area was already checked by `check`, which makes this infallible.
+            output.extend(extension.to_bytes()?);
+
+            if let Some((colors, size)) = self.postage_stamp {
+                output.extend([size.width as u8, size.height as u8]);
+                self.write_image_data(colors, size, Compression::Uncompressed, &mut output);
+            }
+        }
+
+        let mut developer_directory_offset = 0;
+        if !self.developer_tags.is_empty() {
+            // The data is stored after the directory to make sure that the data isn't treated
+            // as part of the image data by decoders.
+            developer_directory_offset = file_offset(output.position);
+            let mut data_offset =
+                output.position + 2 + self.developer_tags.len() * DEVELOPER_TAG_LENGTH;
+
+            output.extend((self.developer_tags.len() as u16).to_le_bytes());
+            for tag in self.developer_tags {
+                output.extend(tag.tag.to_le_bytes());
+                output.extend(file_offset(data_offset).to_le_bytes());
+                output.extend(file_offset(tag.data.len()).to_le_bytes());
+
+                data_offset += tag.data.len();
+            }
+
+            for tag in self.developer_tags {
+                output.extend(tag.data.iter().copied());
+            }
+        }
+
+        if extension_area_offset != 0 || developer_directory_offset != 0 {
+            output.extend(footer_bytes(
+                extension_area_offset,
+                developer_directory_offset,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Encodes an image into a byte slice.
+    ///
+    /// The colors must be stored in row-major order, starting at the top left corner of the
+    /// image. On success the number of bytes written to `output` is returned.
+    ///
+    /// [`EncodeError::BufferTooSmall`] is returned if `output` is too small to hold the encoded
+    /// image. The error contains the required size, which can be determined in advance by using
+    /// [`max_encoded_size`] for uncompressed images. The content of `output` is unspecified if an
+    /// error is returned.
+    ///
+    /// [`EncodeError::BufferTooSmall`]: enum.EncodeError.html#variant.BufferTooSmall
+    /// [`max_encoded_size`]: #method.max_encoded_size
+    pub fn encode_into(&self, colors: &[C], output: &mut [u8]) -> Result<usize, EncodeError> {
+        self.header()?;
+        self.check(colors)?;
+
+        // The size of uncompressed images is known in advance.
+        if self.compression == Compression::Uncompressed {
+            let required = self.max_encoded_size();
+            if required > output.len() {
+                return Err(EncodeError::BufferTooSmall { required });
+            }
+        }
+
+        let mut writer = SliceWriter {
+            output,
+            position: 0,
+        };
+        self.encode(colors, &mut writer)?;
+
+        if writer.position <= writer.output.len() {
+            Ok(writer.position)
+        } else {
+            Err(EncodeError::BufferTooSmall {
+                required: writer.position,
+            })
+        }
+    }
+
+    /// Returns the maximum size of the encoded image in bytes.
+    ///
+    /// For uncompressed images the returned value is the exact size of the encoded image. The size
+    /// of RLE compressed images depends on the image content and is at most the returned value.
+    pub fn max_encoded_size(&self) -> usize {
+        let pixels = (self.size.width as usize).saturating_mul(self.size.height as usize);
+        let pixel_bytes = usize::from(self.pixel_depth().bytes());
+        let bytes_per_pixel = match self.compression {
+            Compression::Uncompressed => pixel_bytes,
+            // In the worst case every pixel is stored in a separate packet.
+            Compression::Rle => pixel_bytes + 1,
+        };
+
+        let color_map_length = self.color_map.map_or(0, <[C]>::len);
+
+        let mut size = pixels
+            .saturating_mul(bytes_per_pixel)
+            .saturating_add(HEADER_LENGTH + self.image_id.len())
+            .saturating_add(color_map_length.saturating_mul(usize::from(C::BPP.bytes())));
+
+        if self.extension_area().is_some() {
+            size = size.saturating_add(EXTENSION_AREA_LENGTH);
+        }
+
+        if let Some((_, stamp_size)) = self.postage_stamp {
+            let stamp_pixels =
+                (stamp_size.width as usize).saturating_mul(stamp_size.height as usize);
+            size = size.saturating_add(stamp_pixels.saturating_mul(pixel_bytes).saturating_add(2));
+        }
+
+        if !self.developer_tags.is_empty() {
+            size = self.developer_tags.iter().fold(
+                size.saturating_add(2 + self.developer_tags.len() * DEVELOPER_TAG_LENGTH),
+                |size, tag| size.saturating_add(tag.data.len()),
+            );
+        }
+
+        if self.extension_area().is_some() || !self.developer_tags.is_empty() {
+            size = size.saturating_add(TGA_FOOTER_LENGTH);
+        }
+
+        size
+    }
+
+    /// Checks the image data and the optional sections before anything is written.
+    fn check(&self, colors: &[C]) -> Result<(), EncodeError> {
+        check_length(colors, self.size)?;
+        self.check_colors(colors)?;
+
+        if let Some(extension) = self.extension_area() {
+            extension.to_bytes()?;
+        }
+
+        if let Some((colors, size)) = self.postage_stamp {
+            if size.width > 255 || size.height > 255 {
+                return Err(EncodeError::ImageTooLarge);
+            }
+
+            check_length(colors, size)?;
+            self.check_colors(colors)?;
+        }
+
+        if self.developer_tags.len() > usize::from(u16::MAX) {
+            return Err(EncodeError::TooManyDeveloperTags);
+        }
+
+        // The offsets of the extension area and the developer directory are stored as 32 bit
+        // values. The maximum size is used because the size of RLE compressed images isn't known
+        // until the image data is encoded.
+        if (self.extension_area().is_some() || !self.developer_tags.is_empty())
+            && u32::try_from(self.max_encoded_size()).is_err()
+        {
+            return Err(EncodeError::ImageTooLarge);
+        }
+
+        Ok(())
+    }
+
+    /// Checks that all colors are contained in the color map.
+    fn check_colors(&self, colors: &[C]) -> Result<(), EncodeError> {
+        if let Some(color_map) = self.color_map {
+            if colors.iter().any(|color| !color_map.contains(color)) {
+                return Err(EncodeError::ColorNotInColorMap);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the number of bits per pixel in the image data.
+    fn pixel_depth(&self) -> Bpp {
+        match self.color_map {
+            Some(color_map) if color_map.len() > 256 => Bpp::Bits16,
+            Some(_) => Bpp::Bits8,
+            None => C::BPP,
+        }
+    }
+
+    /// Returns the extension area, if the file contains one.
+    fn extension_area(&self) -> Option<TgaExtension<'a>> {
+        self.extension
+            .or_else(|| self.postage_stamp.map(|_| TgaExtension::default()))
+    }
+
+    /// Writes the image data.
+    fn write_image_data<E>(
+        &self,
+        colors: &[C],
+        size: Size,
+        compression: Compression,
+        output: &mut E,
+    ) where
+        E: Extend<u8>,
+    {
+        for row in self.rows(colors, size) {
+            match compression {
+                Compression::Uncompressed => {
+                    for column in 0..row.len() {
+                        output.extend(raw_bytes(row.get(column), row.bpp));
+                    }
+                }
+                Compression::Rle => encode_rle_row(&row, output),
+            }
+        }
+    }
+
+    /// Returns the header of the encoded image.
+    fn header(&self) -> Result<TgaHeader, EncodeError> {
+        let width = u16::try_from(self.size.width).map_err(|_| EncodeError::ImageTooLarge)?;
+        let height = u16::try_from(self.size.height).map_err(|_| EncodeError::ImageTooLarge)?;
+        let id_len = u8::try_from(self.image_id.len()).map_err(|_| EncodeError::ImageIdTooLong)?;
+
+        let (data_type, color_map_len, color_map_depth) = match self.color_map {
+            Some(color_map) => {
+                if C::BPP == Bpp::Bits8 {
+                    return Err(EncodeError::UnsupportedColorMap);
+                }
+
+                let length =
+                    u16::try_from(color_map.len()).map_err(|_| EncodeError::ColorMapTooLarge)?;
+
+                (DataType::ColorMapped, length, Some(C::BPP))
+            }
+            None => (C::DATA_TYPE, 0, None),
+        };
+
+        Ok(TgaHeader {
+            id_len,
+            has_color_map: self.color_map.is_some(),
+            data_type,
+            compression: self.compression,
+            color_map_start: 0,
+            color_map_len,
+            color_map_depth,
+            x_origin: 0,
+            y_origin: 0,
+            width,
+            height,
+            pixel_depth: self.pixel_depth(),
+            image_origin: self.image_origin,
+            alpha_channel_depth: C::ALPHA_CHANNEL_DEPTH,
+        })
+    }
+
+    /// Returns an iterator over the rows in the order they are stored in the image data.
+    fn rows<'b>(&'b self, colors: &'b [C], size: Size) -> impl Iterator<Item = Row<'b, C>> {
+        let width = size.width as usize;
+        let height = size.height as usize;
+        let image_origin = self.image_origin;
+        let color_map = self.color_map;
+        let bpp = self.pixel_depth();
+
+        (0..height).map(move |index| {
+            let y = if image_origin.is_bottom() {
+                height - 1 - index
+            } else {
+                index
+            };
+
+            Row {
+                colors: &colors[y * width..(y + 1) * width],
+                reversed: image_origin.is_right(),
+                color_map,
+                bpp,
+            }
+        })
+    }
+}
+
+/// Checks that the number of colors matches the image size.
+fn check_length<C>(colors: &[C], size: Size) -> Result<(), EncodeError> {
+    let expected = size.width as usize * size.height as usize;
+    if colors.len() != expected {
+        return Err(EncodeError::MismatchedLength {
+            expected,
+            actual: colors.len(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Converts a position in the file into an offset.
+///
+/// The position can't be larger than `u32::MAX`, which is checked by `TgaEncoder::check` before
+/// anything is written.
+fn file_offset(position: usize) -> u32 {
+    debug_assert!(u32::try_from(position).is_ok());
+
+    position as u32
+}
+
+/// Wraps an output and counts the written bytes.
+struct CountingWriter<'a, E> {
+    output: &'a mut E,
+    position: usize,
+}
+
+impl<E: Extend<u8>> Extend<u8> for CountingWriter<'_, E> {
+    fn extend<T: IntoIterator<Item = u8>>(&mut self, iter: T) {
+        let position = &mut self.position;
+        self.output
+            .extend(iter.into_iter().inspect(|_| *position += 1));
+    }
+}
+
+/// Writes bytes into a slice and counts the bytes which didn't fit into the slice.
+struct SliceWriter<'a> {
+    output: &'a mut [u8],
+    position: usize,
+}
+
+impl Extend<u8> for SliceWriter<'_> {
+    fn extend<T: IntoIterator<Item = u8>>(&mut self, iter: T) {
+        for byte in iter {
+            if let Some(output) = self.output.get_mut(self.position) {
+                *output = byte;
+            }
+            self.position += 1;
+        }
+    }
+}
+
+/// Maximum number of pixels in a RLE packet.
+const MAX_PACKET_LENGTH: usize = 128;
+
+/// A single row of the image.
+struct Row<'a, C> {
+    /// Colors in top left origin order.
+    colors: &'a [C],
+
+    /// Whether the colors are stored from right to left.
+    reversed: bool,
+
+    /// Color map, which is used to convert the colors into color indices.
+    color_map: Option<&'a [C]>,
+
+    /// Number of bits per pixel in the image data.
+    bpp: Bpp,
+}
+
+impl<C: EncoderColor> Row<'_, C> {
+    fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    /// Returns the raw color or color index at the given index in file order.
+    fn get(&self, index: usize) -> u32 {
+        let x = if self.reversed {
+            self.colors.len() - 1 - index
+        } else {
+            index
+        };
+
+        let color = self.colors[x];
+        match self.color_map {
+            // All colors were checked to be in the color map by `TgaEncoder::check`.
+            Some(color_map) => color_map
+                .iter()
+                .position(|entry| *entry == color)
+                .unwrap_or_default() as u32,
+            None => color.into_raw(),
+        }
+    }
+}
+
+/// Returns the bytes of a raw value in the order they are stored in the file.
+fn raw_bytes(raw: u32, bpp: Bpp) -> impl Iterator<Item = u8> {
+    raw.to_le_bytes().into_iter().take(usize::from(bpp.bytes()))
+}
+
+/// Encodes a single row into run-length and raw packets.
+fn encode_rle_row<C, E>(row: &Row<'_, C>, output: &mut E)
+where
+    C: EncoderColor,
+    E: Extend<u8>,
+{
+    let width = row.len();
+    let mut start = 0;
+
+    while start < width {
+        let color = row.get(start);
+
+        let run_length = (start..width)
+            .take(MAX_PACKET_LENGTH)
+            .take_while(|index| row.get(*index) == color)
+            .count();
+
+        if run_length > 1 {
+            output.extend([0x80 | (run_length - 1) as u8]);
+            output.extend(raw_bytes(color, row.bpp));
+
+            start += run_length;
+        } else {
+            // Extend the raw packet until the next run starts.
+            let mut length = 1;
+            while start + length < width
+                && length < MAX_PACKET_LENGTH
+                && !(start + length + 1 < width
+                    && row.get(start + length) == row.get(start + length + 1))
+            {
+                length += 1;
+            }
+
+            output.extend([(length - 1) as u8]);
+            for index in start..start + length {
+                output.extend(raw_bytes(row.get(index), row.bpp));
+            }
+
+            start += length;
+        }
+    }
+}
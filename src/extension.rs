@@ -0,0 +1,286 @@
+use core::time::Duration;
+use nom::{
+    bytes::complete::take,
+    combinator::{map, map_opt},
+    number::complete::{le_u16, le_u32, le_u8},
+    IResult,
+};
+
+use crate::encode_error::EncodeError;
+
+/// TGA 2.0 extension area length in bytes
+pub(crate) const EXTENSION_AREA_LENGTH: usize = 495;
+
+/// Date and time.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct DateTime {
+    /// Year (e.g. 1990)
+    pub year: u16,
+
+    /// Month (1 - 12)
+    pub month: u16,
+
+    /// Day (1 - 31)
+    pub day: u16,
+
+    /// Hour (0 - 23)
+    pub hour: u16,
+
+    /// Minute (0 - 59)
+    pub minute: u16,
+
+    /// Second (0 - 59)
+    pub second: u16,
+}
+
+/// TGA 2.0 extension area.
+///
+/// String fields are returned as raw bytes without the terminating null bytes. Fields that are
+/// marked as unused in the TGA file are set to `None`.
+///
+/// See <https://www.fileformat.info/format/tga/egff.htm> for a detailed description of the fields.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct TgaExtension<'a> {
+    /// Author name
+    pub author_name: &'a [u8],
+
+    /// Author comments, consisting of four lines
+    pub author_comments: [&'a [u8]; 4],
+
+    /// Date and time the image was saved
+    pub date_time: Option<DateTime>,
+
+    /// Job name or ID
+    pub job_name: &'a [u8],
+
+    /// Time spent on the job
+    pub job_time: Duration,
+
+    /// Software ID of the application that created the image
+    pub software_id: &'a [u8],
+
+    /// Software version multiplied by 100 (e.g. 417 for version 4.17)
+    pub software_version: Option<u16>,
+
+    /// Software version letter (e.g. `b'b'` for version 4.17b)
+    pub software_version_letter: Option<u8>,
+
+    /// Key color in A:R:G:B format
+    pub key_color: u32,
+
+    /// Pixel aspect ratio as numerator and denominator
+    pub pixel_aspect_ratio: Option<(u16, u16)>,
+
+    /// Gamma value as numerator and denominator
+    pub gamma: Option<(u16, u16)>,
+
+    /// Color correction table offset from the start of the file
+    pub color_correction_offset: Option<usize>,
+
+    /// Postage stamp image offset from the start of the file
+    pub postage_stamp_offset: Option<usize>,
+
+    /// Scan line table offset from the start of the file
+    pub scan_line_offset: Option<usize>,
+
+    /// Attributes type
+    pub attributes_type: u8,
+}
+
+impl<'a> TgaExtension<'a> {
+    /// Parses the extension area.
+    ///
+    /// Returns `None` if the extension area is invalid.
+    pub(crate) fn parse(extension_area: &'a [u8]) -> Option<Self> {
+        parse_extension(extension_area)
+            .ok()
+            .map(|(_, extension)| extension)
+    }
+
+    /// Returns the binary representation of the extension area.
+    pub(crate) fn to_bytes(self) -> Result<[u8; EXTENSION_AREA_LENGTH], EncodeError> {
+        let mut bytes = [0; EXTENSION_AREA_LENGTH];
+        let mut output = &mut bytes[..];
+
+        write(&mut output, &(EXTENSION_AREA_LENGTH as u16).to_le_bytes());
+        write_string(&mut output, self.author_name, 41)?;
+        for comment in self.author_comments {
+            write_string(&mut output, comment, 81)?;
+        }
+
+        let date_time = self.date_time.unwrap_or_default();
+        for value in [
+            date_time.month,
+            date_time.day,
+            date_time.year,
+            date_time.hour,
+            date_time.minute,
+            date_time.second,
+        ] {
+            write(&mut output, &value.to_le_bytes());
+        }
+
+        write_string(&mut output, self.job_name, 41)?;
+
+        let job_time = self.job_time.as_secs();
+        let hours = u16::try_from(job_time / 3600).unwrap_or(u16::MAX);
+        for value in [hours, (job_time / 60 % 60) as u16, (job_time % 60) as u16] {
+            write(&mut output, &value.to_le_bytes());
+        }
+
+        write_string(&mut output, self.software_id, 41)?;
+        write(
+            &mut output,
+            &self.software_version.unwrap_or_default().to_le_bytes(),
+        );
+        write(&mut output, &[self.software_version_letter.unwrap_or(b' ')]);
+        write(&mut output, &self.key_color.to_le_bytes());
+
+        for ratio in [self.pixel_aspect_ratio, self.gamma] {
+            let (numerator, denominator) = ratio.unwrap_or_default();
+            write(&mut output, &numerator.to_le_bytes());
+            write(&mut output, &denominator.to_le_bytes());
+        }
+
+        for offset in [
+            self.color_correction_offset,
+            self.postage_stamp_offset,
+            self.scan_line_offset,
+        ] {
+            let offset = u32::try_from(offset.unwrap_or_default())
+                .map_err(|_| EncodeError::ImageTooLarge)?;
+            write(&mut output, &offset.to_le_bytes());
+        }
+
+        write(&mut output, &[self.attributes_type]);
+        debug_assert!(output.is_empty());
+
+        Ok(bytes)
+    }
+}
+
+/// Writes bytes to the start of `output` and advances it.
+fn write(output: &mut &mut [u8], bytes: &[u8]) {
+    let (start, rest) = core::mem::take(output).split_at_mut(bytes.len());
+    start.copy_from_slice(bytes);
+    *output = rest;
+}
+
+/// Writes a fixed length, null terminated string.
+fn write_string(output: &mut &mut [u8], string: &[u8], length: usize) -> Result<(), EncodeError> {
+    if string.len() >= length {
+        return Err(EncodeError::ExtensionFieldTooLong);
+    }
+
+    let (field, rest) = core::mem::take(output).split_at_mut(length);
+    field[..string.len()].copy_from_slice(string);
+    *output = rest;
+
+    Ok(())
+}
+
+fn parse_extension(input: &[u8]) -> IResult<&[u8], TgaExtension<'_>> {
+    let (input, _size) = map_opt(le_u16, |size| {
+        Some(size).filter(|size| usize::from(*size) >= EXTENSION_AREA_LENGTH)
+    })(input)?;
+    let (input, author_name) = string(41)(input)?;
+    let (input, comment_1) = string(81)(input)?;
+    let (input, comment_2) = string(81)(input)?;
+    let (input, comment_3) = string(81)(input)?;
+    let (input, comment_4) = string(81)(input)?;
+    let (input, date_time) = date_time(input)?;
+    let (input, job_name) = string(41)(input)?;
+    let (input, job_time) = job_time(input)?;
+    let (input, software_id) = string(41)(input)?;
+    let (input, software_version) = le_u16(input)?;
+    let (input, software_version_letter) = le_u8(input)?;
+    let (input, key_color) = le_u32(input)?;
+    let (input, pixel_aspect_ratio) = ratio(input)?;
+    let (input, gamma) = ratio(input)?;
+    let (input, color_correction_offset) = offset(input)?;
+    let (input, postage_stamp_offset) = offset(input)?;
+    let (input, scan_line_offset) = offset(input)?;
+    let (input, attributes_type) = le_u8(input)?;
+
+    Ok((
+        input,
+        TgaExtension {
+            author_name,
+            author_comments: [comment_1, comment_2, comment_3, comment_4],
+            date_time,
+            job_name,
+            job_time,
+            software_id,
+            software_version: Some(software_version).filter(|version| *version != 0),
+            software_version_letter: Some(software_version_letter)
+                .filter(|letter| *letter != b' ' && *letter != 0),
+            key_color,
+            pixel_aspect_ratio,
+            gamma,
+            color_correction_offset,
+            postage_stamp_offset,
+            scan_line_offset,
+            attributes_type,
+        },
+    ))
+}
+
+/// Parses a fixed length, null terminated string.
+fn string(length: usize) -> impl Fn(&[u8]) -> IResult<&[u8], &[u8]> {
+    move |input| {
+        map(take(length), |bytes: &[u8]| {
+            let end = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+            &bytes[..end]
+        })(input)
+    }
+}
+
+fn date_time(input: &[u8]) -> IResult<&[u8], Option<DateTime>> {
+    let (input, month) = le_u16(input)?;
+    let (input, day) = le_u16(input)?;
+    let (input, year) = le_u16(input)?;
+    let (input, hour) = le_u16(input)?;
+    let (input, minute) = le_u16(input)?;
+    let (input, second) = le_u16(input)?;
+
+    let date_time = DateTime {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+    };
+
+    Ok((
+        input,
+        Some(date_time).filter(|date_time| *date_time != DateTime::default()),
+    ))
+}
+
+fn job_time(input: &[u8]) -> IResult<&[u8], Duration> {
+    let (input, hours) = le_u16(input)?;
+    let (input, minutes) = le_u16(input)?;
+    let (input, seconds) = le_u16(input)?;
+
+    let seconds = u64::from(hours) * 3600 + u64::from(minutes) * 60 + u64::from(seconds);
+
+    Ok((input, Duration::from_secs(seconds)))
+}
+
+/// Parses a ratio, which is unused if the denominator is zero.
+fn ratio(input: &[u8]) -> IResult<&[u8], Option<(u16, u16)>> {
+    let (input, numerator) = le_u16(input)?;
+    let (input, denominator) = le_u16(input)?;
+
+    Ok((
+        input,
+        Some((numerator, denominator)).filter(|(_, denominator)| *denominator != 0),
+    ))
+}
+
+fn offset(input: &[u8]) -> IResult<&[u8], Option<usize>> {
+    map(le_u32, |offset| {
+        Some(offset as usize).filter(|offset| *offset != 0)
+    })(input)
+}
@@ -0,0 +1,165 @@
+use embedded_graphics::{
+    pixelcolor::{Gray8, Rgb555, Rgb888},
+    prelude::*,
+    primitives::Rectangle,
+};
+
+use crate::{
+    palette::Palette,
+    raw_iter::{raw_value, RlePacket, RlePackets},
+    Compression, ImageOrigin, Tga, REVERSE_BUFFER_LENGTH,
+};
+
+/// TGA image which draws RLE runs using `fill_solid`.
+///
+/// `FillRuns` draws each run packet in an RLE compressed image with a single
+/// [`DrawTarget::fill_solid`] call per row, instead of passing the repeated colors to
+/// [`DrawTarget::fill_contiguous`] one by one. See the [`fill_runs`] method for additional
+/// information.
+///
+/// [`DrawTarget::fill_solid`]: embedded_graphics::draw_target::DrawTarget::fill_solid
+/// [`DrawTarget::fill_contiguous`]: embedded_graphics::draw_target::DrawTarget::fill_contiguous
+/// [`fill_runs`]: struct.Tga.html#method.fill_runs
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct FillRuns<'a, C> {
+    tga: Tga<'a, C>,
+}
+
+impl<'a, C> FillRuns<'a, C> {
+    pub(crate) fn new(tga: Tga<'a, C>) -> Self {
+        Self { tga }
+    }
+}
+
+impl<C> OriginDimensions for FillRuns<'_, C> {
+    fn size(&self) -> Size {
+        self.tga.size()
+    }
+}
+
+impl<C> ImageDrawable for FillRuns<'_, C>
+where
+    C: PixelColor + From<Gray8> + From<Rgb555> + From<Rgb888>,
+{
+    type Color = C;
+
+    fn draw<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let raw = self.tga.as_raw();
+        if raw.compression() != Compression::Rle {
+            return self.tga.draw(target);
+        }
+
+        if self.tga.bounding_box().is_zero_sized() {
+            return Ok(());
+        }
+
+        let mut spans = Spans::new(self.tga.size(), raw.image_origin());
+        let bytes_per_pixel = usize::from(raw.image_data_bpp().bytes());
+        let palette = Palette::new(&self.tga);
+        let mut buffer = [self.tga.fallback_color(); REVERSE_BUFFER_LENGTH];
+
+        for packet in RlePackets::new(raw) {
+            match packet {
+                RlePacket::Run { count, value } => {
+                    let color = palette.color(value);
+
+                    let mut remaining = u32::from(count);
+                    while let Some(span) = spans.next_span(remaining) {
+                        target.fill_solid(&span, color)?;
+                        remaining -= span.size.width;
+                    }
+                }
+                RlePacket::Raw { data } => {
+                    let mut values = data.chunks_exact(bytes_per_pixel);
+
+                    while let Some(span) = spans.next_span(values.len() as u32) {
+                        let mut colors = (&mut values)
+                            .take(span.size.width as usize)
+                            .map(|bytes| palette.color(raw_value(bytes)));
+
+                        if spans.mirrored {
+                            Tga::<C>::fill_reversed(target, &span, &mut colors, &mut buffer)?;
+                        } else {
+                            target.fill_contiguous(&span, &mut colors)?;
+
+                            // Draw targets aren't required to consume all colors, e.g. if the
+                            // span is outside the clipping area.
+                            colors.for_each(drop);
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn draw_sub_image<D>(&self, target: &mut D, area: &Rectangle) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.draw(&mut target.translated(-area.top_left).clipped(area))
+    }
+}
+
+/// Splits consecutive pixels in the image data into horizontal spans in display coordinates.
+#[derive(Debug)]
+struct Spans {
+    size: Size,
+    bottom: bool,
+    mirrored: bool,
+
+    /// Index of the next pixel in the current row, in image data order.
+    x: u32,
+    /// Index of the current row, in image data order.
+    row: u32,
+}
+
+impl Spans {
+    fn new(size: Size, origin: ImageOrigin) -> Self {
+        Self {
+            size,
+            bottom: origin.is_bottom(),
+            mirrored: origin.is_right(),
+            x: 0,
+            row: 0,
+        }
+    }
+
+    /// Returns the area of the next span with a maximum length of `length` pixels.
+    ///
+    /// Spans never cross row boundaries. `None` is returned if `length` is `0` or if the end of
+    /// the image was reached.
+    fn next_span(&mut self, length: u32) -> Option<Rectangle> {
+        if length == 0 || self.row >= self.size.height {
+            return None;
+        }
+
+        let width = length.min(self.size.width - self.x);
+
+        let x = if self.mirrored {
+            self.size.width - self.x - width
+        } else {
+            self.x
+        };
+        let y = if self.bottom {
+            self.size.height - 1 - self.row
+        } else {
+            self.row
+        };
+
+        self.x += width;
+        if self.x >= self.size.width {
+            self.x = 0;
+            self.row += 1;
+        }
+
+        Some(Rectangle::new(
+            Point::new(x as i32, y as i32),
+            Size::new(width, 1),
+        ))
+    }
+}
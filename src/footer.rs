@@ -1,8 +1,10 @@
 use core::num::NonZeroUsize;
-use nom::{bytes::complete::tag, combinator::map, number::complete::le_u32, IResult, Needed};
 
 /// TGA footer length in bytes
-const TGA_FOOTER_LENGTH: usize = 26;
+pub(crate) const TGA_FOOTER_LENGTH: usize = 26;
+
+/// Signature at the end of the footer.
+const SIGNATURE: &[u8; 18] = b"TRUEVISION-XFILE.\0";
 
 /// TGA footer structure, referenced from <http://tfc.duke.free.fr/coding/tga_specs.pdf>
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
@@ -21,22 +23,51 @@ impl TgaFooter {
     /// Parses the TGA footer.
     ///
     /// Returns `None` if the file doesn't contain a valid footer.
-    pub fn parse(image_data: &[u8]) -> Option<Self> {
-        parse_footer(image_data).ok().map(|(_, footer)| footer)
+    pub const fn parse(image_data: &[u8]) -> Option<Self> {
+        let footer_start = match image_data.len().checked_sub(TGA_FOOTER_LENGTH) {
+            Some(footer_start) => footer_start,
+            None => return None,
+        };
+        let (_, footer) = image_data.split_at(footer_start);
+
+        let mut i = 0;
+        while i < SIGNATURE.len() {
+            if footer[8 + i] != SIGNATURE[i] {
+                return None;
+            }
+            i += 1;
+        }
+
+        Some(Self {
+            footer_start,
+            extension_area_offset: offset(footer, 0),
+            developer_directory_offset: offset(footer, 4),
+        })
+    }
+
+    /// Returns the extension area offset.
+    pub(crate) fn extension_area_offset(&self) -> Option<usize> {
+        self.extension_area_offset.map(NonZeroUsize::get)
     }
 
     /// Returns the length of the footer section of the TGA file.
     ///
     /// The length includes the footer, extension area and developer directory.
-    pub fn length(&self, image_data: &[u8]) -> usize {
+    pub const fn length(&self, image_data: &[u8]) -> usize {
         let mut length = TGA_FOOTER_LENGTH;
 
         if let Some(offset) = self.extension_area_offset {
-            length = length.max(image_data.len() - offset.get());
+            let section_length = image_data.len().saturating_sub(offset.get());
+            if section_length > length {
+                length = section_length;
+            }
         }
 
         if let Some(offset) = self.developer_directory_offset {
-            length = length.max(image_data.len() - offset.get());
+            let section_length = image_data.len().saturating_sub(offset.get());
+            if section_length > length {
+                length = section_length;
+            }
         }
 
         length
@@ -46,17 +77,15 @@ impl TgaFooter {
     ///
     /// Returns `None` if the file doesn't contain an extension area.
     pub fn extension_area<'a>(&self, image_data: &'a [u8]) -> Option<&'a [u8]> {
-        self.extension_area_offset
-            .map(NonZeroUsize::get)
-            .and_then(|start| {
-                let end = self
-                    .developer_directory_offset
-                    .map(NonZeroUsize::get)
-                    .filter(|offset| *offset > start)
-                    .unwrap_or(self.footer_start);
-
-                image_data.get(start..end)
-            })
+        self.extension_area_offset().and_then(|start| {
+            let end = self
+                .developer_directory_offset
+                .map(NonZeroUsize::get)
+                .filter(|offset| *offset > start)
+                .unwrap_or(self.footer_start);
+
+            image_data.get(start..end)
+        })
     }
 
     /// Returns the developer directory.
@@ -77,29 +106,32 @@ impl TgaFooter {
     }
 }
 
-fn offset(input: &[u8]) -> IResult<&[u8], Option<NonZeroUsize>> {
-    map(le_u32, |offset| NonZeroUsize::new(offset as usize))(input)
+/// Returns the binary representation of a footer.
+///
+/// Offsets which are set to `0` indicate that the file doesn't contain the corresponding section.
+pub(crate) fn footer_bytes(
+    extension_area_offset: u32,
+    developer_directory_offset: u32,
+) -> [u8; TGA_FOOTER_LENGTH] {
+    let mut bytes = [0; TGA_FOOTER_LENGTH];
+
+    bytes[0..4].copy_from_slice(&extension_area_offset.to_le_bytes());
+    bytes[4..8].copy_from_slice(&developer_directory_offset.to_le_bytes());
+    bytes[8..].copy_from_slice(SIGNATURE);
+
+    bytes
 }
 
-fn parse_footer(input: &[u8]) -> IResult<&[u8], TgaFooter> {
-    let footer_start = input
-        .len()
-        .checked_sub(TGA_FOOTER_LENGTH)
-        .ok_or(nom::Err::Incomplete(Needed::Size(
-            NonZeroUsize::new(TGA_FOOTER_LENGTH).unwrap(),
-        )))?;
-    let input = &input[footer_start..input.len()];
-
-    let (input, extension_area_offset) = offset(input)?;
-    let (input, developer_directory_offset) = offset(input)?;
-    let (input, _) = tag("TRUEVISION-XFILE.\0")(input)?;
-
-    Ok((
-        input,
-        TgaFooter {
-            footer_start,
-            extension_area_offset,
-            developer_directory_offset,
-        },
-    ))
+/// Reads a little endian offset from the footer.
+///
+/// Offsets which are set to `0` are returned as `None`.
+const fn offset(footer: &[u8], start: usize) -> Option<NonZeroUsize> {
+    let offset = u32::from_le_bytes([
+        footer[start],
+        footer[start + 1],
+        footer[start + 2],
+        footer[start + 3],
+    ]);
+
+    NonZeroUsize::new(offset as usize)
 }
@@ -1,11 +1,10 @@
-use nom::{
-    combinator::{map, map_opt, map_res},
-    number::complete::{le_u16, le_u8},
-    IResult,
-};
+use nom::{bytes::complete::take, combinator::map_opt, IResult};
 
 use crate::parse_error::ParseError;
 
+/// TGA header length in bytes
+pub(crate) const HEADER_LENGTH: usize = 18;
+
 /// Bits per pixel.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 #[non_exhaustive]
@@ -21,7 +20,7 @@ pub enum Bpp {
 }
 
 impl Bpp {
-    fn new(value: u8) -> Option<Self> {
+    const fn new(value: u8) -> Option<Self> {
         Some(match value {
             8 => Self::Bits8,
             16 => Self::Bits16,
@@ -31,16 +30,8 @@ impl Bpp {
         })
     }
 
-    fn parse(input: &[u8]) -> IResult<&[u8], Self> {
-        map_opt(le_u8, Bpp::new)(input)
-    }
-
-    fn parse_opt(input: &[u8]) -> IResult<&[u8], Option<Self>> {
-        map(le_u8, Bpp::new)(input)
-    }
-
     /// Returns the number of bits.
-    pub fn bits(self) -> u8 {
+    pub const fn bits(self) -> u8 {
         match self {
             Self::Bits8 => 8,
             Self::Bits16 => 16,
@@ -50,7 +41,7 @@ impl Bpp {
     }
 
     /// Returns the number of bytes needed to store values with this bit depth.
-    pub fn bytes(self) -> u8 {
+    pub const fn bytes(self) -> u8 {
         match self {
             Self::Bits8 => 1,
             Self::Bits16 => 2,
@@ -82,7 +73,21 @@ pub enum DataType {
     BlackAndWhite,
 }
 
-fn parse_image_type(image_type: u8) -> Result<(DataType, Compression), ParseError> {
+fn image_type(data_type: DataType, compression: Compression) -> u8 {
+    let image_type = match data_type {
+        DataType::NoData => 0,
+        DataType::ColorMapped => 1,
+        DataType::TrueColor => 2,
+        DataType::BlackAndWhite => 3,
+    };
+
+    match compression {
+        Compression::Uncompressed => image_type,
+        Compression::Rle => image_type | 0x8,
+    }
+}
+
+const fn parse_image_type(image_type: u8) -> Result<(DataType, Compression), ParseError> {
     if image_type & !0b1011 != 0 || image_type == 8 {
         return Err(ParseError::UnsupportedImageType(image_type));
     }
@@ -117,7 +122,7 @@ pub enum ImageOrigin {
 }
 
 impl ImageOrigin {
-    fn from_image_descriptor(value: u8) -> Self {
+    const fn from_image_descriptor(value: u8) -> Self {
         match (value & 0x30) >> 4 {
             0 => Self::BottomLeft,
             1 => Self::BottomRight,
@@ -126,9 +131,24 @@ impl ImageOrigin {
         }
     }
 
+    fn to_image_descriptor(self) -> u8 {
+        let value = match self {
+            Self::BottomLeft => 0,
+            Self::BottomRight => 1,
+            Self::TopLeft => 2,
+            Self::TopRight => 3,
+        };
+
+        value << 4
+    }
+
     pub(crate) fn is_bottom(self) -> bool {
         matches!(self, Self::BottomLeft | Self::BottomRight)
     }
+
+    pub(crate) fn is_right(self) -> bool {
+        matches!(self, Self::TopRight | Self::BottomRight)
+    }
 }
 
 /// TGA header.
@@ -181,48 +201,70 @@ pub struct TgaHeader {
 
 impl TgaHeader {
     pub(crate) fn parse(input: &[u8]) -> IResult<&[u8], Self> {
-        let (input, id_len) = le_u8(input)?;
-        let (input, has_color_map) = has_color_map(input)?;
-        let (input, (data_type, compression)) = map_res(le_u8, parse_image_type)(input)?;
-        let (input, color_map_start) = le_u16(input)?;
-        let (input, color_map_len) = le_u16(input)?;
-        let (input, color_map_depth) = Bpp::parse_opt(input)?;
-        let (input, x_origin) = le_u16(input)?;
-        let (input, y_origin) = le_u16(input)?;
-        let (input, width) = le_u16(input)?;
-        let (input, height) = le_u16(input)?;
-        let (input, pixel_depth) = Bpp::parse(input)?;
-
-        let (input, image_descriptor) = le_u8(input)?;
-        let image_origin = ImageOrigin::from_image_descriptor(image_descriptor);
-        let alpha_channel_depth = image_descriptor & 0xF;
-
-        Ok((
-            input,
-            TgaHeader {
-                id_len,
-                has_color_map,
-                data_type,
-                compression,
-                color_map_start,
-                color_map_len,
-                color_map_depth,
-                x_origin,
-                y_origin,
-                width,
-                height,
-                pixel_depth,
-                image_origin,
-                alpha_channel_depth,
-            },
-        ))
+        map_opt(take(HEADER_LENGTH), Self::from_bytes)(input)
     }
-}
 
-fn has_color_map(input: &[u8]) -> IResult<&[u8], bool> {
-    map_res(le_u8, |b| match b {
-        0 => Ok(false),
-        1 => Ok(true),
-        _ => Err(ParseError::ColorMap),
-    })(input)
+    /// Parses the header from the start of a TGA file.
+    ///
+    /// Returns `None` if `bytes` is shorter than the header or if the header is invalid.
+    pub(crate) const fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < HEADER_LENGTH {
+            return None;
+        }
+
+        let has_color_map = match bytes[1] {
+            0 => false,
+            1 => true,
+            _ => return None,
+        };
+
+        let (data_type, compression) = match parse_image_type(bytes[2]) {
+            Ok(image_type) => image_type,
+            Err(_) => return None,
+        };
+
+        let pixel_depth = match Bpp::new(bytes[16]) {
+            Some(pixel_depth) => pixel_depth,
+            None => return None,
+        };
+
+        let image_descriptor = bytes[17];
+
+        Some(TgaHeader {
+            id_len: bytes[0],
+            has_color_map,
+            data_type,
+            compression,
+            color_map_start: u16::from_le_bytes([bytes[3], bytes[4]]),
+            color_map_len: u16::from_le_bytes([bytes[5], bytes[6]]),
+            color_map_depth: Bpp::new(bytes[7]),
+            x_origin: u16::from_le_bytes([bytes[8], bytes[9]]),
+            y_origin: u16::from_le_bytes([bytes[10], bytes[11]]),
+            width: u16::from_le_bytes([bytes[12], bytes[13]]),
+            height: u16::from_le_bytes([bytes[14], bytes[15]]),
+            pixel_depth,
+            image_origin: ImageOrigin::from_image_descriptor(image_descriptor),
+            alpha_channel_depth: image_descriptor & 0xF,
+        })
+    }
+
+    /// Returns the binary representation of the header.
+    pub(crate) fn to_bytes(self) -> [u8; HEADER_LENGTH] {
+        let mut bytes = [0; HEADER_LENGTH];
+
+        bytes[0] = self.id_len;
+        bytes[1] = u8::from(self.has_color_map);
+        bytes[2] = image_type(self.data_type, self.compression);
+        bytes[3..5].copy_from_slice(&self.color_map_start.to_le_bytes());
+        bytes[5..7].copy_from_slice(&self.color_map_len.to_le_bytes());
+        bytes[7] = self.color_map_depth.map_or(0, Bpp::bits);
+        bytes[8..10].copy_from_slice(&self.x_origin.to_le_bytes());
+        bytes[10..12].copy_from_slice(&self.y_origin.to_le_bytes());
+        bytes[12..14].copy_from_slice(&self.width.to_le_bytes());
+        bytes[14..16].copy_from_slice(&self.height.to_le_bytes());
+        bytes[16] = self.pixel_depth.bits();
+        bytes[17] = self.image_origin.to_image_descriptor() | (self.alpha_channel_depth & 0xF);
+
+        bytes
+    }
 }
@@ -0,0 +1,68 @@
+use crate::{parse_error::ParseError, RawTga};
+
+/// Includes a TGA image and checks it at compile time.
+///
+/// The file is included by using [`include_bytes!`], which means that the path is relative to
+/// the current file. The header and color map of the image are checked at compile time by calling
+/// [`RawTga::check`]. Invalid or unsupported images result in a compile error, instead of an error
+/// that is returned by [`Tga::from_slice`] at runtime.
+///
+/// The macro evaluates to a `Tga<'static, C>`, where the color type `C` is inferred from the
+/// context. The expression can be used to initialize constants and statics, which parses the
+/// image at compile time.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{image::Image, mock_display::MockDisplay, pixelcolor::Rgb888, prelude::*};
+/// use tinytga::{include_tga, Tga};
+///
+/// static CHESSBOARD: Tga<Rgb888> = include_tga!("../tests/chessboard_4px_rle.tga");
+///
+/// let mut display = MockDisplay::new();
+/// Image::new(&CHESSBOARD, Point::zero()).draw(&mut display)?;
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// Images with an unsupported bit depth are rejected at compile time:
+///
+/// ```compile_fail
+/// use embedded_graphics::pixelcolor::Rgb888;
+/// use tinytga::{include_tga, Tga};
+///
+/// // "error_index_bpp.tga" uses 24 bit color indices.
+/// let tga: Tga<Rgb888> = include_tga!("../tests/error_index_bpp.tga");
+/// ```
+///
+/// [`include_bytes!`]: core::include_bytes
+/// [`RawTga::check`]: struct.RawTga.html#method.check
+/// [`Tga::from_slice`]: struct.Tga.html#method.from_slice
+#[macro_export]
+macro_rules! include_tga {
+    ($file:expr $(,)?) => {{
+        const DATA: &[u8] = ::core::include_bytes!($file);
+        const _: () = $crate::__check_included_tga(DATA);
+
+        match $crate::Tga::from_slice(DATA) {
+            ::core::result::Result::Ok(tga) => tga,
+            ::core::result::Result::Err(_) => ::core::unreachable!(),
+        }
+    }};
+}
+
+/// Checks an image that was included by the `include_tga` macro.
+///
+/// Panics with a description of the error if the image isn't supported.
+#[doc(hidden)]
+pub const fn __check_included_tga(data: &[u8]) {
+    match RawTga::check(data) {
+        Ok(_) => {}
+        Err(ParseError::Header) => panic!("invalid TGA header"),
+        Err(ParseError::ColorMap) => panic!("invalid TGA color map"),
+        Err(ParseError::UnsupportedBpp(_)) => panic!("unsupported TGA bit depth"),
+        Err(ParseError::UnsupportedTgaType(_, _)) => {
+            panic!("unsupported combination of TGA image type and bit depth")
+        }
+        Err(_) => panic!("invalid TGA image"),
+    }
+}
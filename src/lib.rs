@@ -120,35 +120,105 @@
 #![deny(unused_import_braces)]
 #![deny(unused_qualifications)]
 
+mod alpha_blended;
+#[cfg(feature = "embedded-io-async")]
+mod async_reader;
 mod color_map;
+mod color_type;
+mod developer_directory;
+mod draw_error;
+mod dynamic_tga;
+mod encode_error;
+mod encoder;
+mod extension;
+mod fill_runs;
 mod footer;
 mod header;
+mod include_tga;
+mod palette;
 mod parse_error;
 mod pixels;
 mod raw_iter;
 mod raw_tga;
+#[cfg(feature = "embedded-io")]
+mod read_error;
+#[cfg(feature = "embedded-io")]
+mod reader;
+mod scan_line_table;
+mod scanlines;
+#[cfg(feature = "embedded-io")]
+mod stream_decoder;
+mod writer;
 
-use core::marker::PhantomData;
 use embedded_graphics::{
+    image::GetPixel,
     pixelcolor::{
-        raw::{RawU16, RawU24, RawU8},
+        raw::{RawU16, RawU24, RawU32, RawU8},
         Gray8, Rgb555, Rgb888,
     },
     prelude::*,
     primitives::Rectangle,
 };
+use palette::Palette;
 use raw_iter::{RawColors, Rle, Uncompressed};
 
 pub use crate::{
+    alpha_blended::AlphaBlended,
     color_map::ColorMap,
+    color_type::ColorType,
+    developer_directory::{DeveloperTag, DeveloperTags},
+    draw_error::DrawError,
+    dynamic_tga::DynamicTga,
+    encode_error::EncodeError,
+    encoder::{EncoderColor, TgaEncoder},
+    extension::{DateTime, TgaExtension},
+    fill_runs::FillRuns,
     header::{Bpp, Compression, DataType, ImageOrigin, TgaHeader},
     parse_error::ParseError,
     pixels::Pixels,
-    raw_iter::{RawPixel, RawPixels},
+    raw_iter::{RawPixel, RawPixels, RawRow},
     raw_tga::RawTga,
+    scan_line_table::ScanLineTable,
+    scanlines::{RawScanlines, Scanlines},
+    writer::TgaWriter,
 };
 
+#[doc(hidden)]
+pub use crate::include_tga::__check_included_tga;
+
+#[cfg(feature = "embedded-io-async")]
+pub use crate::async_reader::AsyncTgaReader;
+#[cfg(feature = "embedded-io")]
+pub use crate::{
+    read_error::{ReadDrawError, ReadError},
+    reader::TgaReader,
+};
+
+/// Maximum number of pixels that are reversed at once when drawing images with the origin on the
+/// right side.
+const REVERSE_BUFFER_LENGTH: usize = 64;
+
 /// TGA image.
+///
+/// # Alpha channel
+///
+/// 32 bit true color images and color maps with 32 bit entries contain an 8 bit alpha channel in
+/// addition to the color channels. By default the alpha channel is ignored and the pixels are
+/// converted to the target color type as if they were stored as [`Rgb888`] colors. The raw
+/// values returned by [`RawTga::pixels`] and [`ColorMap::get_raw`] still contain the alpha channel
+/// in the upper 8 bits.
+///
+/// The [`alpha_blended`] and [`draw_alpha_blended`] methods can be used to draw images with
+/// transparency. The alpha channel is determined by [`TgaHeader::alpha_channel_depth`]: 32 bit
+/// colors use an 8 bit alpha channel and 16 bit colors use the most significant bit as a 1 bit
+/// alpha channel. For color mapped images the alpha channel is stored in the color map entries.
+///
+/// [`Rgb888`]: embedded_graphics::pixelcolor::Rgb888
+/// [`RawTga::pixels`]: struct.RawTga.html#method.pixels
+/// [`ColorMap::get_raw`]: struct.ColorMap.html#method.get_raw
+/// [`alpha_blended`]: #method.alpha_blended
+/// [`draw_alpha_blended`]: #method.draw_alpha_blended
+/// [`TgaHeader::alpha_channel_depth`]: struct.TgaHeader.html#structfield.alpha_channel_depth
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub struct Tga<'a, C> {
     /// Raw TGA file.
@@ -156,8 +226,11 @@ pub struct Tga<'a, C> {
 
     image_color_type: ColorType,
 
-    /// Color type.
-    target_color_type: PhantomData<C>,
+    /// Color used for pixels with invalid color indices.
+    ///
+    /// `None` is used for the default fallback color, which makes it possible to create images
+    /// in const contexts.
+    fallback_color: Option<C>,
 }
 
 impl<'a, C> Tga<'a, C>
@@ -165,28 +238,101 @@ where
     C: PixelColor + From<Gray8> + From<Rgb555> + From<Rgb888>,
 {
     /// Parses a TGA image from a byte slice.
-    pub fn from_slice(data: &'a [u8]) -> Result<Self, ParseError> {
-        let raw = RawTga::from_slice(data)?;
-
-        let image_color_type = match (raw.color_bpp(), raw.data_type()) {
-            (Bpp::Bits8, DataType::BlackAndWhite) => ColorType::Gray8,
-            (Bpp::Bits16, DataType::ColorMapped) => ColorType::Rgb555,
-            (Bpp::Bits16, DataType::TrueColor) => ColorType::Rgb555,
-            (Bpp::Bits24, DataType::ColorMapped) => ColorType::Rgb888,
-            (Bpp::Bits24, DataType::TrueColor) => ColorType::Rgb888,
-            _ => {
-                return Err(ParseError::UnsupportedTgaType(
-                    raw.data_type(),
-                    raw.color_bpp(),
-                ));
-            }
+    ///
+    /// This method is a `const fn`, which makes it possible to parse images at compile time. See
+    /// the [`include_tga`] macro for a convenient way to include images in const contexts.
+    ///
+    /// [`include_tga`]: macro.include_tga.html
+    pub const fn from_slice(data: &'a [u8]) -> Result<Self, ParseError> {
+        let raw = match RawTga::from_slice(data) {
+            Ok(raw) => raw,
+            Err(error) => return Err(error),
         };
 
-        Ok(Tga {
+        match raw.color_type() {
+            Ok(image_color_type) => Ok(Self::from_raw(raw, image_color_type)),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Creates a new image from a raw TGA image with a known color type.
+    pub(crate) const fn from_raw(raw: RawTga<'a>, image_color_type: ColorType) -> Self {
+        Tga {
             raw,
             image_color_type,
-            target_color_type: PhantomData,
-        })
+            fallback_color: None,
+        }
+    }
+
+    /// Sets the color that is used for pixels with invalid color indices.
+    ///
+    /// Color mapped images can contain color indices which are outside the range of the color
+    /// map. These pixels are replaced by the fallback color, which defaults to black, when the
+    /// image is drawn or its pixels are accessed. Use [`try_draw`] or [`check_color_indices`] to
+    /// detect invalid indices instead.
+    ///
+    /// [`try_draw`]: #method.try_draw
+    /// [`check_color_indices`]: #method.check_color_indices
+    pub fn with_fallback_color(mut self, fallback_color: C) -> Self {
+        self.fallback_color = Some(fallback_color);
+        self
+    }
+
+    /// Returns the color that is used for pixels with invalid color indices.
+    fn fallback_color(&self) -> C {
+        self.fallback_color
+            .unwrap_or_else(|| C::from(Rgb888::BLACK))
+    }
+
+    /// Checks if all color indices in the image data are inside the range of the color map.
+    ///
+    /// Returns [`ParseError::InvalidColorIndex`] with the first invalid index if the image data
+    /// contains a color index that isn't part of the color map. Images without a color map are
+    /// always valid.
+    ///
+    /// # Performance
+    ///
+    /// The entire image data is decoded every time this method is called.
+    ///
+    /// [`ParseError::InvalidColorIndex`]: enum.ParseError.html#variant.InvalidColorIndex
+    pub fn check_color_indices(&self) -> Result<(), ParseError> {
+        if let Some(color_map) = self.raw.color_map() {
+            if let Some(pixel) = self
+                .raw
+                .pixels()
+                .find(|pixel| color_map.get_raw(pixel.color as usize).is_none())
+            {
+                return Err(ParseError::InvalidColorIndex(pixel.color));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Draws the image to a draw target, checking the color indices first.
+    ///
+    /// Unlike [`ImageDrawable::draw`], which replaces invalid color indices by the
+    /// [fallback color], this method returns an error and doesn't draw anything if the image
+    /// contains invalid color indices. The image is drawn with its top left corner at the origin
+    /// of the draw target.
+    ///
+    /// [`ImageDrawable::draw`]: embedded_graphics::image::ImageDrawable::draw
+    /// [fallback color]: #method.with_fallback_color
+    pub fn try_draw<D>(&self, target: &mut D) -> Result<(), DrawError<D::Error>>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        self.check_color_indices().map_err(DrawError::Parse)?;
+
+        self.draw(target).map_err(DrawError::DrawTarget)
+    }
+
+    /// Returns the native color type of this image.
+    ///
+    /// The colors in the image are converted from the native color type to `C` when the image
+    /// is drawn or its pixels are accessed.
+    pub fn color_type(&self) -> ColorType {
+        self.image_color_type
     }
 
     /// Returns a reference to the raw TGA image.
@@ -198,11 +344,180 @@ where
         &self.raw
     }
 
+    /// Returns the postage stamp image.
+    ///
+    /// The postage stamp is a small version of the image, which can be used as a thumbnail. The
+    /// returned image can be drawn or accessed like any other `Tga` image. `None` is returned if
+    /// the image contains no postage stamp.
+    ///
+    /// See [`RawTga::postage_stamp`] for more information.
+    ///
+    /// [`RawTga::postage_stamp`]: struct.RawTga.html#method.postage_stamp
+    pub fn postage_stamp(&self) -> Option<Self> {
+        self.raw.postage_stamp().map(|raw| Self { raw, ..*self })
+    }
+
     /// Returns an iterator over the pixels in this image.
     pub fn pixels(&self) -> Pixels<'_, C> {
         Pixels::new(self)
     }
 
+    /// Returns a row iterator over the pixels in this image.
+    ///
+    /// The rows are returned in top to bottom display order and the colors in each row from left
+    /// to right, regardless of the image origin. This makes it possible to pass the rows directly
+    /// to line based display drivers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{pixelcolor::Rgb888, prelude::*};
+    /// use tinytga::Tga;
+    ///
+    /// let data = include_bytes!("../tests/chessboard_4px_rle.tga");
+    /// let tga = Tga::<Rgb888>::from_slice(data).unwrap();
+    ///
+    /// let mut scanlines = tga.scanlines();
+    /// let mut row = [Rgb888::BLACK; 4];
+    /// while let Some(y) = scanlines.next_row(&mut row).unwrap() {
+    ///     // Send the row at position `y` to the display.
+    /// #   assert!(y < 4);
+    /// }
+    /// ```
+    ///
+    /// # Performance
+    ///
+    /// See [`RawTga::scanlines`] for more information.
+    ///
+    /// [`RawTga::scanlines`]: struct.RawTga.html#method.scanlines
+    pub fn scanlines(&self) -> Scanlines<'_, C> {
+        Scanlines::new(self)
+    }
+
+    /// Decodes the image into a buffer.
+    ///
+    /// The colors are stored in row-major order, starting at the top left corner of the image,
+    /// regardless of the image origin. Color indices outside the range of the color map are
+    /// replaced by the [fallback color].
+    ///
+    /// The length of `buffer` must be equal to the number of pixels in the image, otherwise
+    /// [`ParseError::MismatchedLength`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{pixelcolor::Rgb888, prelude::*};
+    /// use tinytga::Tga;
+    ///
+    /// let data = include_bytes!("../tests/chessboard_4px_rle.tga");
+    /// let tga = Tga::<Rgb888>::from_slice(data).unwrap();
+    ///
+    /// let mut framebuffer = [Rgb888::BLACK; 4 * 4];
+    /// tga.decode_into(&mut framebuffer).unwrap();
+    /// ```
+    ///
+    /// [fallback color]: #method.with_fallback_color
+    /// [`ParseError::MismatchedLength`]: enum.ParseError.html#variant.MismatchedLength
+    pub fn decode_into(&self, buffer: &mut [C]) -> Result<(), ParseError> {
+        self.raw.decode_with(buffer.len(), 1, |index, raw| {
+            buffer[index] = self.convert_raw(raw, 0).0;
+        })
+    }
+
+    /// Returns an alpha blended version of this image.
+    ///
+    /// The returned image uses the alpha channel to blend the image with a solid `background`
+    /// color when it is drawn. Fully transparent pixels aren't drawn and fully opaque pixels are
+    /// drawn without blending.
+    pub fn alpha_blended(&self, background: C) -> AlphaBlended<'a, C>
+    where
+        C: Into<Rgb888>,
+    {
+        AlphaBlended::new(*self, background)
+    }
+
+    /// Returns a version of this image that draws RLE runs using `fill_solid`.
+    ///
+    /// Run packets in RLE compressed images store a single color for up to 128 consecutive
+    /// pixels. The returned image draws each run with one [`DrawTarget::fill_solid`] call per
+    /// row, which can be much faster than drawing the individual pixels if the draw target
+    /// accelerates filling solid areas, e.g. by sending a single fill command to the display.
+    /// All other pixels are drawn using [`DrawTarget::fill_contiguous`].
+    ///
+    /// Uncompressed images are drawn in the same way as this image.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use embedded_graphics::{image::Image, mock_display::MockDisplay, pixelcolor::Rgb888, prelude::*};
+    /// use tinytga::Tga;
+    ///
+    /// let data = include_bytes!("../tests/chessboard_4px_rle.tga");
+    /// let tga = Tga::<Rgb888>::from_slice(data).unwrap();
+    ///
+    /// let mut display = MockDisplay::new();
+    /// Image::new(&tga.fill_runs(), Point::zero()).draw(&mut display)?;
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    ///
+    /// # Performance
+    ///
+    /// Draw targets that don't override `fill_solid` fall back to drawing the runs pixel by
+    /// pixel. Images with many short runs can be slower to draw than the original image,
+    /// because each run results in a separate draw call.
+    ///
+    /// [`DrawTarget::fill_solid`]: embedded_graphics::draw_target::DrawTarget::fill_solid
+    /// [`DrawTarget::fill_contiguous`]: embedded_graphics::draw_target::DrawTarget::fill_contiguous
+    pub fn fill_runs(&self) -> FillRuns<'a, C> {
+        FillRuns::new(*self)
+    }
+
+    /// Draws the image by blending it with the current content of the draw target.
+    ///
+    /// The top left corner of the image will be drawn at `position`. Fully transparent pixels
+    /// aren't drawn and fully opaque pixels are drawn without blending. Partially transparent
+    /// pixels are blended with the color returned by [`GetPixel::pixel`] for the same position
+    /// and are skipped if the draw target returns `None`.
+    ///
+    /// [`GetPixel::pixel`]: embedded_graphics::image::GetPixel::pixel
+    pub fn draw_alpha_blended<D>(&self, target: &mut D, position: Point) -> Result<(), D::Error>
+    where
+        C: Into<Rgb888>,
+        D: DrawTarget<Color = C> + GetPixel<Color = C>,
+    {
+        alpha_blended::draw_onto(self, target, position)
+    }
+
+    /// Converts a raw pixel value into a color and an alpha value.
+    ///
+    /// For color mapped images the raw value is used as an index into the color map. The alpha
+    /// value is `255` if `alpha_channel_depth` is `0`.
+    pub(crate) fn convert_raw(&self, mut raw: u32, alpha_channel_depth: u8) -> (C, u8) {
+        if let Some(color_map) = self.raw.color_map() {
+            match color_map.get_raw(raw as usize) {
+                Some(entry) => raw = entry,
+                None => return (self.fallback_color(), u8::MAX),
+            }
+        }
+
+        let alpha = match self.image_color_type {
+            // 16 bit colors use the most significant bit as a 1 bit alpha channel.
+            ColorType::Rgb555 if alpha_channel_depth > 0 => {
+                if raw & 0x8000 != 0 {
+                    u8::MAX
+                } else {
+                    0
+                }
+            }
+            ColorType::Argb8888 if alpha_channel_depth > 0 => (raw >> 24) as u8,
+            _ => u8::MAX,
+        };
+
+        let color = self.image_color_type.convert(raw);
+
+        (color, alpha)
+    }
+
     fn draw_colors<D>(
         &self,
         target: &mut D,
@@ -218,41 +533,85 @@ where
 
         let origin = self.raw.image_origin();
 
-        // TGA files with the origin in the top left corner can be drawn using `fill_contiguous`.
-        // All other origins are drawn by falling back to `draw_iter`.
-        match origin {
-            ImageOrigin::TopLeft => target.fill_contiguous(&bounding_box, colors),
-            ImageOrigin::BottomLeft => {
-                let mut row_rect =
-                    Rectangle::new(Point::zero(), Size::new(bounding_box.size.width, 1));
-
-                for y in bounding_box.rows().rev() {
-                    row_rect.top_left.y = y;
-                    let row_colors = (&mut colors).take(bounding_box.size.width as usize);
-                    target.fill_contiguous(&row_rect, row_colors)?;
-                }
+        // TGA files with the origin in the top left corner can be drawn using a single
+        // `fill_contiguous` call. All other origins are drawn row by row.
+        if origin.is_right() {
+            let mut row_rect = Rectangle::new(Point::zero(), Size::new(bounding_box.size.width, 1));
+            let mut buffer = [self.fallback_color(); REVERSE_BUFFER_LENGTH];
+
+            let mut draw_row = |y| {
+                row_rect.top_left.y = y;
+                Self::fill_reversed(target, &row_rect, &mut colors, &mut buffer)
+            };
+
+            if origin.is_bottom() {
+                bounding_box.rows().rev().try_for_each(&mut draw_row)
+            } else {
+                bounding_box.rows().try_for_each(&mut draw_row)
+            }
+        } else if origin.is_bottom() {
+            let mut row_rect = Rectangle::new(Point::zero(), Size::new(bounding_box.size.width, 1));
+
+            for y in bounding_box.rows().rev() {
+                row_rect.top_left.y = y;
+                let mut row_colors = (&mut colors).take(bounding_box.size.width as usize);
+                target.fill_contiguous(&row_rect, &mut row_colors)?;
+
+                // Draw targets aren't required to consume all colors, e.g. if the row is outside
+                // the clipping area. Skip the remaining colors in this row to make sure the next
+                // row starts at the correct position.
+                row_colors.for_each(drop);
+            }
 
-                Ok(())
+            Ok(())
+        } else {
+            target.fill_contiguous(&bounding_box, colors)
+        }
+    }
+
+    /// Draws a row of colors that are stored from right to left.
+    ///
+    /// The colors are reversed in segments of up to `buffer.len()` pixels, which are then drawn
+    /// using `fill_contiguous`.
+    fn fill_reversed<D>(
+        target: &mut D,
+        row_rect: &Rectangle,
+        colors: &mut impl Iterator<Item = C>,
+        buffer: &mut [C],
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let mut end_x = row_rect.size.width as usize;
+
+        while end_x > 0 {
+            let length = end_x.min(buffer.len());
+            let segment = &mut buffer[0..length];
+
+            let mut filled = 0;
+            for (entry, color) in segment.iter_mut().rev().zip(&mut *colors) {
+                *entry = color;
+                filled += 1;
             }
-            ImageOrigin::TopRight => {
-                let max_x = bounding_box.bottom_right().map(|p| p.x).unwrap_or_default();
-
-                bounding_box
-                    .points()
-                    .zip(colors)
-                    .map(|(p, c)| Pixel(Point::new(max_x - p.x, p.y), c))
-                    .draw(target)
+
+            // Only the filled part at the end of the segment is drawn if the colors iterator ran
+            // out, e.g. in truncated images.
+            if filled > 0 {
+                let segment_rect = Rectangle::new(
+                    row_rect.top_left + Point::new((end_x - filled) as i32, 0),
+                    Size::new(filled as u32, 1),
+                );
+                target
+                    .fill_contiguous(&segment_rect, segment[length - filled..].iter().copied())?;
             }
-            ImageOrigin::BottomRight => {
-                let bottom_right = bounding_box.bottom_right().unwrap_or_default();
-
-                bounding_box
-                    .points()
-                    .zip(colors)
-                    .map(|(p, c)| Pixel(bottom_right - p, c))
-                    .draw(target)
+
+            if filled < length {
+                break;
             }
+            end_x -= length;
         }
+
+        Ok(())
     }
 
     fn draw_regular<D, CI, F>(
@@ -268,6 +627,22 @@ where
         self.draw_colors(target, colors.map(|c| CI::from(c).into()))
     }
 
+    fn draw_argb8888<D, F>(
+        &self,
+        target: &mut D,
+        colors: RawColors<'a, RawU32, F>,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+        RawColors<'a, RawU32, F>: Iterator<Item = RawU32>,
+    {
+        // The alpha channel is discarded by only using the lower 24 bits.
+        self.draw_colors(
+            target,
+            colors.map(|c| Rgb888::from(RawU24::from_u32(c.into_inner())).into()),
+        )
+    }
+
     fn draw_color_mapped<D, R, F>(
         &self,
         target: &mut D,
@@ -279,33 +654,13 @@ where
         R::Storage: Into<u32>,
         RawColors<'a, R, F>: Iterator<Item = R>,
     {
-        let color_map = if let Some(color_map) = self.raw.color_map() {
-            color_map
-        } else {
-            return Ok(());
-        };
-
-        match self.image_color_type {
-            ColorType::Rgb555 => {
-                let colors = indices.map(|index| {
-                    let index = index.into_inner().into() as usize;
-                    color_map.get::<Rgb555>(index).unwrap().into()
-                });
+        // Color maps with 8 bit grayscale entries are rejected by `from_slice`, which is why
+        // `draw`, `draw_sub_image` and `fill_runs` can use the same palette for all color mapped
+        // images.
+        let palette = Palette::new(self);
+        let colors = indices.map(|index| palette.color(index.into_inner().into()));
 
-                self.draw_colors(target, colors)
-            }
-            ColorType::Rgb888 => {
-                let colors = indices.map(|index| {
-                    let index = index.into_inner().into() as usize;
-                    color_map.get::<Rgb888>(index).unwrap().into()
-                });
-
-                self.draw_colors(target, colors)
-            }
-            // Color mapped Gray8 images aren't supported.  Using a color map for Gray8 images
-            // doesn't make sense, because this encoding will always be larger than a type 3 image.
-            ColorType::Gray8 => Ok(()),
-        }
+        self.draw_colors(target, colors)
     }
 }
 
@@ -386,7 +741,26 @@ where
                     }
                 }
             },
-            Bpp::Bits32 => Ok(()),
+            Bpp::Bits32 => match self.raw.compression() {
+                Compression::Uncompressed => {
+                    let colors = RawColors::<RawU32, Uncompressed>::new(&self.raw);
+
+                    if self.raw.color_map().is_some() {
+                        self.draw_color_mapped(target, colors)
+                    } else {
+                        self.draw_argb8888(target, colors)
+                    }
+                }
+                Compression::Rle => {
+                    let colors = RawColors::<RawU32, Rle>::new(&self.raw);
+
+                    if self.raw.color_map().is_some() {
+                        self.draw_color_mapped(target, colors)
+                    } else {
+                        self.draw_argb8888(target, colors)
+                    }
+                }
+            },
         }
     }
 
@@ -394,13 +768,81 @@ where
     where
         D: DrawTarget<Color = Self::Color>,
     {
-        self.draw(&mut target.translated(-area.top_left).clipped(area))
-    }
-}
+        let clipped_area = area.intersection(&self.bounding_box());
+        if clipped_area.is_zero_sized() {
+            return Ok(());
+        }
 
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
-pub(crate) enum ColorType {
-    Gray8,
-    Rgb555,
-    Rgb888,
+        let origin = self.raw.image_origin();
+        let width = clipped_area.size.width;
+        let skip = if origin.is_right() {
+            // The pixels in each row are stored from right to left.
+            (self.size().width - width) as usize - clipped_area.top_left.x as usize
+        } else {
+            clipped_area.top_left.x as usize
+        };
+
+        let palette = Palette::new(self);
+        let mut buffer = [self.fallback_color(); REVERSE_BUFFER_LENGTH];
+
+        // Draws the part of a row that is inside the area. The pixels before the area are skipped
+        // without decoding them.
+        let mut draw_row = |y: i32, row: &mut RawRow<'_>| {
+            let mut colors = row
+                .skip(skip)
+                .take(width as usize)
+                .map(|raw| palette.color(raw));
+
+            let row_rect = Rectangle::new(
+                Point::new(clipped_area.top_left.x, y) - area.top_left,
+                Size::new(width, 1),
+            );
+
+            if origin.is_right() {
+                Self::fill_reversed(target, &row_rect, &mut colors, &mut buffer)
+            } else {
+                target.fill_contiguous(&row_rect, colors)
+            }
+        };
+
+        let scan_line_table = match self.raw.compression() {
+            Compression::Uncompressed => None,
+            Compression::Rle => self.raw.scan_line_table(),
+        };
+
+        if self.raw.compression() == Compression::Rle && scan_line_table.is_none() {
+            // Without a scan line table each row in a RLE compressed image can only be accessed
+            // by decoding all previous rows. The rows inside the area are therefore decoded
+            // sequentially, in the order they are stored in the image data.
+            let first_row = if origin.is_bottom() {
+                self.size().height - clipped_area.top_left.y as u32 - clipped_area.size.height
+            } else {
+                clipped_area.top_left.y as u32
+            };
+            let mut row = RawRow::skip_rows(&self.raw, first_row);
+
+            let mut draw_next_row = |y| {
+                draw_row(y, &mut row)?;
+                row.continue_with_next_row(self.size().width);
+
+                Ok(())
+            };
+
+            if origin.is_bottom() {
+                clipped_area.rows().rev().try_for_each(&mut draw_next_row)
+            } else {
+                clipped_area.rows().try_for_each(&mut draw_next_row)
+            }
+        } else {
+            // Rows in uncompressed images and RLE compressed images with a scan line table can be
+            // accessed directly.
+            for y in clipped_area.rows() {
+                if let Some(mut row) = self.raw.row_with_table(y as u32, scan_line_table.as_ref()) {
+                    draw_row(y, &mut row)?;
+                }
+            }
+
+            Ok(())
+        }
+    }
 }
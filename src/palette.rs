@@ -0,0 +1,71 @@
+use embedded_graphics::{
+    pixelcolor::{Gray8, Rgb555, Rgb888},
+    prelude::*,
+};
+
+use crate::Tga;
+
+/// Maximum number of color map entries that are converted in advance.
+const PALETTE_LENGTH: usize = 256;
+
+/// Color map entries converted into the color type of an image.
+///
+/// Converting the color map once per draw call turns the color lookup for each pixel into a
+/// single array access. Only the color map entries of color mapped images are converted, up to a
+/// maximum of 256 entries. Entries in larger color maps and the raw values of images without a
+/// color map are converted when they are accessed.
+///
+/// The palette is only used while an image is drawn and isn't stored in any iterator, to keep
+/// the memory usage of the iterators low.
+#[derive(Debug)]
+pub(crate) struct Palette<'a, C> {
+    tga: Tga<'a, C>,
+
+    /// First color index.
+    start_index: usize,
+    /// Number of converted entries.
+    length: usize,
+
+    /// Converted entries, `None` if the image contains no color map.
+    colors: Option<[C; PALETTE_LENGTH]>,
+}
+
+impl<'a, C> Palette<'a, C>
+where
+    C: PixelColor + From<Gray8> + From<Rgb555> + From<Rgb888>,
+{
+    /// Converts the color map of an image.
+    pub(crate) fn new(tga: &Tga<'a, C>) -> Self {
+        let mut palette = Self {
+            tga: *tga,
+            start_index: 0,
+            length: 0,
+            colors: None,
+        };
+
+        if let Some(color_map) = tga.as_raw().color_map() {
+            palette.start_index = usize::from(color_map.start_index());
+            palette.length = color_map.length().min(PALETTE_LENGTH);
+
+            let mut colors = [tga.fallback_color(); PALETTE_LENGTH];
+            for (entry, color) in colors[0..palette.length].iter_mut().enumerate() {
+                *color = tga.convert_raw((palette.start_index + entry) as u32, 0).0;
+            }
+            palette.colors = Some(colors);
+        }
+
+        palette
+    }
+
+    /// Converts a raw value into a color.
+    ///
+    /// For color mapped images the raw value is used as an index into the color map.
+    pub(crate) fn color(&self, raw: u32) -> C {
+        let entry = (raw as usize).wrapping_sub(self.start_index);
+
+        match &self.colors {
+            Some(colors) if entry < self.length => colors[entry],
+            _ => self.tga.convert_raw(raw, 0).0,
+        }
+    }
+}
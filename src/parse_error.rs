@@ -29,4 +29,18 @@ pub enum ParseError {
 
     /// Unsupported combination of image type and bits per pixel.
     UnsupportedTgaType(DataType, Bpp),
+
+    /// A color index in the image data is outside the range of the color map.
+    InvalidColorIndex(u32),
+
+    /// Mismatched buffer length.
+    ///
+    /// The length of the buffer passed to a `decode_into` method doesn't match the image size.
+    MismatchedLength {
+        /// Expected buffer length.
+        expected: usize,
+
+        /// Actual buffer length.
+        actual: usize,
+    },
 }
@@ -1,12 +1,9 @@
 use embedded_graphics::{
-    pixelcolor::{
-        raw::{RawU16, RawU24, RawU8},
-        Gray8, Rgb555, Rgb888,
-    },
+    pixelcolor::{Gray8, Rgb555, Rgb888},
     prelude::*,
 };
 
-use crate::{ColorType, RawPixel, RawPixels, Tga};
+use crate::{RawPixel, RawPixels, Tga};
 
 /// Iterator over individual TGA pixels.
 ///
@@ -17,6 +14,7 @@ use crate::{ColorType, RawPixel, RawPixels, Tga};
 pub struct Pixels<'a, C> {
     tga: &'a Tga<'a, C>,
     raw_pixels: RawPixels<'a>,
+    alpha_channel_depth: u8,
 }
 
 impl<'a, C> Pixels<'a, C>
@@ -27,8 +25,20 @@ where
         Self {
             tga,
             raw_pixels: RawPixels::new(&tga.raw),
+            alpha_channel_depth: tga.raw.header().alpha_channel_depth,
         }
     }
+
+    /// Returns the next pixel and its alpha value.
+    ///
+    /// The alpha value is `255` for all pixels in images without an alpha channel.
+    pub(crate) fn next_with_alpha(&mut self) -> Option<(Pixel<C>, u8)> {
+        let RawPixel { position, color } = self.raw_pixels.next()?;
+
+        let (color, alpha) = self.tga.convert_raw(color, self.alpha_channel_depth);
+
+        Some((Pixel(position, color), alpha))
+    }
 }
 
 impl<C> Iterator for Pixels<'_, C>
@@ -38,21 +48,6 @@ where
     type Item = Pixel<C>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let RawPixel {
-            position,
-            mut color,
-        } = self.raw_pixels.next()?;
-
-        if let Some(color_map) = self.tga.raw.color_map() {
-            color = color_map.get_raw(color as usize).unwrap()
-        }
-
-        let color = match self.tga.image_color_type {
-            ColorType::Gray8 => Gray8::from(RawU8::from_u32(color)).into(),
-            ColorType::Rgb555 => Rgb555::from(RawU16::from_u32(color)).into(),
-            ColorType::Rgb888 => Rgb888::from(RawU24::from_u32(color)).into(),
-        };
-
-        Some(Pixel(position, color))
+        self.next_with_alpha().map(|(pixel, _alpha)| pixel)
     }
 }
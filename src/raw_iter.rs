@@ -26,16 +26,18 @@ pub struct RawColors<'a, R, F> {
 }
 
 impl<'a, R: RawData, F> RawColors<'a, R, F> {
-    pub fn new(raw_tga: &'a RawTga<'a>) -> Self {
+    pub fn new(raw_tga: &RawTga<'a>) -> Self {
         debug_assert_eq!(
             usize::from(raw_tga.image_data_bpp().bits()),
             R::BITS_PER_PIXEL
         );
 
-        let image_data = raw_tga.image_data();
+        Self::from_data(raw_tga.image_data())
+    }
 
+    pub fn from_data(data: &'a [u8]) -> Self {
         Self {
-            remaining_data: image_data,
+            remaining_data: data,
             rle_pixel: 0,
             rle_repeat: 0,
             rle_take_raw: 0,
@@ -45,10 +47,34 @@ impl<'a, R: RawData, F> RawColors<'a, R, F> {
     }
 }
 
+impl<R: RawData, F> RawColors<'_, R, F> {
+    /// Skips `count` values in the remaining data without decoding them.
+    fn skip_data(&mut self, count: usize) {
+        let length = count.saturating_mul(R::BITS_PER_PIXEL / 8);
+
+        self.remaining_data = self
+            .remaining_data
+            .get(length..)
+            .unwrap_or(&self.remaining_data[self.remaining_data.len()..]);
+    }
+}
+
 trait NextColor<R> {
     fn next_color(&mut self) -> Option<R>;
 }
 
+trait NextPacket {
+    /// Reads the header of the next RLE packet.
+    ///
+    /// Returns `None` if the end of the image data was reached.
+    fn next_packet(&mut self) -> Option<()>;
+}
+
+trait SkipColors {
+    /// Skips `count` colors without decoding them.
+    fn skip_colors(&mut self, count: u64);
+}
+
 impl<'a, F> NextColor<RawU8> for RawColors<'a, RawU8, F> {
     fn next_color(&mut self) -> Option<RawU8> {
         self.remaining_data.split_first().map(|(r, rest)| {
@@ -112,6 +138,64 @@ where
     }
 }
 
+impl<R: RawData> SkipColors for RawColors<'_, R, Uncompressed> {
+    fn skip_colors(&mut self, count: u64) {
+        self.skip_data(usize::try_from(count).unwrap_or(usize::MAX));
+    }
+}
+
+impl<R> NextPacket for RawColors<'_, R, Rle>
+where
+    Self: NextColor<R>,
+    R: RawData,
+    R::Storage: Into<u32>,
+{
+    fn next_packet(&mut self) -> Option<()> {
+        let (type_and_count, rest) = self.remaining_data.split_first()?;
+        self.remaining_data = rest;
+
+        // The pixel count is encoded in the lower 7 bits and the actual number of pixels
+        // is one more than the value stored in the packet.
+        let pixel_count = (*type_and_count & 0x7F) + 1;
+
+        // The packet type is encoded in the upper bit: 0 -> Raw, 1 -> Rle
+        if *type_and_count & 0x80 != 0 {
+            let pixel = self.next_color()?;
+
+            self.rle_repeat = pixel_count;
+            self.rle_pixel = pixel.into_inner().into();
+        } else {
+            self.rle_take_raw = pixel_count;
+        }
+
+        Some(())
+    }
+}
+
+impl<R> SkipColors for RawColors<'_, R, Rle>
+where
+    Self: NextColor<R>,
+    R: RawData,
+    R::Storage: Into<u32>,
+{
+    fn skip_colors(&mut self, mut count: u64) {
+        while count > 0 {
+            if self.rle_repeat > 0 {
+                let skipped = count.min(u64::from(self.rle_repeat));
+                self.rle_repeat -= skipped as u8;
+                count -= skipped;
+            } else if self.rle_take_raw > 0 {
+                let skipped = count.min(u64::from(self.rle_take_raw));
+                self.rle_take_raw -= skipped as u8;
+                self.skip_data(skipped as usize);
+                count -= skipped;
+            } else if self.next_packet().is_none() {
+                break;
+            }
+        }
+    }
+}
+
 impl<'a, R> Iterator for RawColors<'a, R, Rle>
 where
     Self: NextColor<R>,
@@ -129,27 +213,84 @@ where
                 self.rle_take_raw -= 1;
                 break self.next_color();
             } else {
-                let (type_and_count, rest) = self.remaining_data.split_first()?;
-                self.remaining_data = rest;
-
-                // The pixel count is encoded in the lower 7 bits and the actual number of pixels
-                // is one more than the value stored in the packet.
-                let pixel_count = (*type_and_count & 0x7F) + 1;
-
-                // The packet type is encoded in the upper bit: 0 -> Raw, 1 -> Rle
-                if *type_and_count & 0x80 != 0 {
-                    let pixel = self.next_color()?;
-
-                    self.rle_repeat = pixel_count;
-                    self.rle_pixel = pixel.into_inner().into();
-                } else {
-                    self.rle_take_raw = pixel_count;
-                }
+                self.next_packet()?;
             }
         }
     }
 }
 
+/// RLE packet.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub(crate) enum RlePacket<'a> {
+    /// Run of `count` pixels with the same raw value.
+    Run { count: u8, value: u32 },
+    /// Raw pixel values, stored as little endian bytes.
+    Raw { data: &'a [u8] },
+}
+
+/// Iterator over the packets in RLE compressed image data.
+///
+/// Incomplete packets at the end of truncated image data are shortened or skipped.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub(crate) struct RlePackets<'a> {
+    remaining_data: &'a [u8],
+    bytes_per_pixel: usize,
+}
+
+impl<'a> RlePackets<'a> {
+    pub(crate) fn new(raw_tga: &RawTga<'a>) -> Self {
+        debug_assert_eq!(raw_tga.compression(), Compression::Rle);
+
+        Self {
+            remaining_data: raw_tga.image_data(),
+            bytes_per_pixel: usize::from(raw_tga.image_data_bpp().bytes()),
+        }
+    }
+}
+
+impl<'a> Iterator for RlePackets<'a> {
+    type Item = RlePacket<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (type_and_count, rest) = self.remaining_data.split_first()?;
+
+        // The pixel count is encoded in the lower 7 bits and the actual number of pixels is one
+        // more than the value stored in the packet.
+        let pixel_count = (*type_and_count & 0x7F) + 1;
+
+        // The packet type is encoded in the upper bit: 0 -> Raw, 1 -> Rle
+        if *type_and_count & 0x80 != 0 {
+            let value = rest.get(0..self.bytes_per_pixel)?;
+            self.remaining_data = &rest[self.bytes_per_pixel..];
+
+            Some(RlePacket::Run {
+                count: pixel_count,
+                value: raw_value(value),
+            })
+        } else {
+            let available = rest.len() - rest.len() % self.bytes_per_pixel;
+            let length = (usize::from(pixel_count) * self.bytes_per_pixel).min(available);
+            if length == 0 {
+                self.remaining_data = &[];
+                return None;
+            }
+
+            let (data, rest) = rest.split_at(length);
+            self.remaining_data = rest;
+
+            Some(RlePacket::Raw { data })
+        }
+    }
+}
+
+/// Converts up to 4 little endian bytes into a raw value.
+pub(crate) fn raw_value(bytes: &[u8]) -> u32 {
+    let mut value = [0; 4];
+    value[0..bytes.len()].copy_from_slice(bytes);
+
+    u32::from_le_bytes(value)
+}
+
 #[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 enum DynamicRawColors<'a> {
     Bpp8Uncompressed(RawColors<'a, RawU8, Uncompressed>),
@@ -162,6 +303,56 @@ enum DynamicRawColors<'a> {
     Bpp32Rle(RawColors<'a, RawU32, Rle>),
 }
 
+impl<'a> DynamicRawColors<'a> {
+    fn new(bpp: Bpp, compression: Compression, data: &'a [u8]) -> Self {
+        match (bpp, compression) {
+            (Bpp::Bits8, Compression::Uncompressed) => {
+                Self::Bpp8Uncompressed(RawColors::from_data(data))
+            }
+            (Bpp::Bits8, Compression::Rle) => Self::Bpp8Rle(RawColors::from_data(data)),
+            (Bpp::Bits16, Compression::Uncompressed) => {
+                Self::Bpp16Uncompressed(RawColors::from_data(data))
+            }
+            (Bpp::Bits16, Compression::Rle) => Self::Bpp16Rle(RawColors::from_data(data)),
+            (Bpp::Bits24, Compression::Uncompressed) => {
+                Self::Bpp24Uncompressed(RawColors::from_data(data))
+            }
+            (Bpp::Bits24, Compression::Rle) => Self::Bpp24Rle(RawColors::from_data(data)),
+            (Bpp::Bits32, Compression::Uncompressed) => {
+                Self::Bpp32Uncompressed(RawColors::from_data(data))
+            }
+            (Bpp::Bits32, Compression::Rle) => Self::Bpp32Rle(RawColors::from_data(data)),
+        }
+    }
+
+    /// Skips `count` raw values without decoding them.
+    fn skip(&mut self, count: u64) {
+        match self {
+            Self::Bpp8Uncompressed(colors) => colors.skip_colors(count),
+            Self::Bpp8Rle(colors) => colors.skip_colors(count),
+            Self::Bpp16Uncompressed(colors) => colors.skip_colors(count),
+            Self::Bpp16Rle(colors) => colors.skip_colors(count),
+            Self::Bpp24Uncompressed(colors) => colors.skip_colors(count),
+            Self::Bpp24Rle(colors) => colors.skip_colors(count),
+            Self::Bpp32Uncompressed(colors) => colors.skip_colors(count),
+            Self::Bpp32Rle(colors) => colors.skip_colors(count),
+        }
+    }
+
+    fn next_raw(&mut self) -> Option<u32> {
+        Some(match self {
+            Self::Bpp8Uncompressed(colors) => u32::from(colors.next()?.into_inner()),
+            Self::Bpp8Rle(colors) => u32::from(colors.next()?.into_inner()),
+            Self::Bpp16Uncompressed(colors) => u32::from(colors.next()?.into_inner()),
+            Self::Bpp16Rle(colors) => u32::from(colors.next()?.into_inner()),
+            Self::Bpp24Uncompressed(colors) => colors.next()?.into_inner(),
+            Self::Bpp24Rle(colors) => colors.next()?.into_inner(),
+            Self::Bpp32Uncompressed(colors) => colors.next()?.into_inner(),
+            Self::Bpp32Rle(colors) => colors.next()?.into_inner(),
+        })
+    }
+}
+
 /// Iterator over individual TGA pixels.
 ///
 /// See the [`pixels`] method for additional information.
@@ -176,24 +367,11 @@ pub struct RawPixels<'a> {
 
 impl<'a> RawPixels<'a> {
     pub(crate) fn new(raw_tga: &'a RawTga<'a>) -> Self {
-        let colors = match (raw_tga.image_data_bpp(), raw_tga.compression()) {
-            (Bpp::Bits8, Compression::Uncompressed) => {
-                DynamicRawColors::Bpp8Uncompressed(RawColors::new(raw_tga))
-            }
-            (Bpp::Bits8, Compression::Rle) => DynamicRawColors::Bpp8Rle(RawColors::new(raw_tga)),
-            (Bpp::Bits16, Compression::Uncompressed) => {
-                DynamicRawColors::Bpp16Uncompressed(RawColors::new(raw_tga))
-            }
-            (Bpp::Bits16, Compression::Rle) => DynamicRawColors::Bpp16Rle(RawColors::new(raw_tga)),
-            (Bpp::Bits24, Compression::Uncompressed) => {
-                DynamicRawColors::Bpp24Uncompressed(RawColors::new(raw_tga))
-            }
-            (Bpp::Bits24, Compression::Rle) => DynamicRawColors::Bpp24Rle(RawColors::new(raw_tga)),
-            (Bpp::Bits32, Compression::Uncompressed) => {
-                DynamicRawColors::Bpp32Uncompressed(RawColors::new(raw_tga))
-            }
-            (Bpp::Bits32, Compression::Rle) => DynamicRawColors::Bpp32Rle(RawColors::new(raw_tga)),
-        };
+        let colors = DynamicRawColors::new(
+            raw_tga.image_data_bpp(),
+            raw_tga.compression(),
+            raw_tga.image_data(),
+        );
 
         let start_y = if raw_tga.image_origin().is_bottom() {
             raw_tga.size().height.saturating_sub(1)
@@ -238,21 +416,82 @@ impl Iterator for RawPixels<'_> {
     fn next(&mut self) -> Option<Self::Item> {
         let position = self.next_position()?;
 
-        let color = match &mut self.colors {
-            DynamicRawColors::Bpp8Uncompressed(colors) => u32::from(colors.next()?.into_inner()),
-            DynamicRawColors::Bpp8Rle(colors) => u32::from(colors.next()?.into_inner()),
-            DynamicRawColors::Bpp16Uncompressed(colors) => u32::from(colors.next()?.into_inner()),
-            DynamicRawColors::Bpp16Rle(colors) => u32::from(colors.next()?.into_inner()),
-            DynamicRawColors::Bpp24Uncompressed(colors) => colors.next()?.into_inner(),
-            DynamicRawColors::Bpp24Rle(colors) => colors.next()?.into_inner(),
-            DynamicRawColors::Bpp32Uncompressed(colors) => colors.next()?.into_inner(),
-            DynamicRawColors::Bpp32Rle(colors) => colors.next()?.into_inner(),
-        };
+        let color = self.colors.next_raw()?;
 
         Some(RawPixel::new(position, color))
     }
 }
 
+/// Iterator over the raw colors in a single row.
+///
+/// See the [`row`] method for additional information.
+///
+/// [`row`]: struct.RawTga.html#method.row
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct RawRow<'a> {
+    colors: DynamicRawColors<'a>,
+    remaining: u32,
+}
+
+impl<'a> RawRow<'a> {
+    /// Creates a new row iterator, which starts at the beginning of `data`.
+    pub(crate) fn new(raw_tga: &RawTga<'a>, data: &'a [u8]) -> Self {
+        Self {
+            colors: DynamicRawColors::new(raw_tga.image_data_bpp(), raw_tga.compression(), data),
+            remaining: raw_tga.size().width,
+        }
+    }
+
+    /// Creates a new row iterator by skipping all previous rows in the image data.
+    pub(crate) fn skip_rows(raw_tga: &RawTga<'a>, rows: u32) -> Self {
+        let mut colors = DynamicRawColors::new(
+            raw_tga.image_data_bpp(),
+            raw_tga.compression(),
+            raw_tga.image_data(),
+        );
+
+        colors.skip(u64::from(rows) * u64::from(raw_tga.size().width));
+
+        Self {
+            colors,
+            remaining: raw_tga.size().width,
+        }
+    }
+}
+
+impl RawRow<'_> {
+    /// Continues decoding the next row in the image data.
+    ///
+    /// Values in the current row that haven't been returned yet are skipped.
+    pub(crate) fn continue_with_next_row(&mut self, width: u32) {
+        self.colors.skip(u64::from(self.remaining));
+        self.remaining = width;
+    }
+}
+
+impl Iterator for RawRow<'_> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        self.colors.next_raw()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        // Skipped values aren't decoded, which makes it possible to efficiently start decoding
+        // a row at a specific X coordinate.
+        let skipped = u32::try_from(n).unwrap_or(u32::MAX).min(self.remaining);
+        self.colors.skip(u64::from(skipped));
+        self.remaining -= skipped;
+
+        self.next()
+    }
+}
+
 /// Pixel with raw pixel color.
 ///
 /// This struct is returned by the [`RawPixels`] iterator.
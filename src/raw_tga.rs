@@ -1,12 +1,17 @@
 use embedded_graphics::prelude::*;
-use nom::{bytes::complete::take, IResult};
+use nom::{bytes::complete::take, number::complete::le_u8, sequence::pair, IResult};
 
 use crate::{
     color_map::ColorMap,
-    footer::TgaFooter,
-    header::{Bpp, ImageOrigin, TgaHeader},
+    color_type::ColorType,
+    developer_directory::DeveloperTags,
+    extension::TgaExtension,
+    footer::{TgaFooter, TGA_FOOTER_LENGTH},
+    header::{Bpp, ImageOrigin, TgaHeader, HEADER_LENGTH},
     parse_error::ParseError,
-    raw_iter::RawPixels,
+    raw_iter::{RawPixels, RawRow},
+    scan_line_table::ScanLineTable,
+    scanlines::RawScanlines,
     Compression, DataType,
 };
 
@@ -44,22 +49,42 @@ pub struct RawTga<'a> {
 
     /// Image origin
     image_origin: ImageOrigin,
+
+    /// Whether this image is the postage stamp of another image
+    is_postage_stamp: bool,
 }
 
 impl<'a> RawTga<'a> {
     /// Parse a TGA image from a byte slice.
-    pub fn from_slice(data: &'a [u8]) -> Result<Self, ParseError> {
-        let input = data;
-        let (input, header) = TgaHeader::parse(input).map_err(|_| ParseError::Header)?;
-        let (input, _image_id) = parse_image_id(input, &header).map_err(|_| ParseError::Header)?;
-        let (input, color_map) = ColorMap::parse(input, &header)?;
+    ///
+    /// This method is a `const fn`, which makes it possible to parse images at compile time.
+    pub const fn from_slice(data: &'a [u8]) -> Result<Self, ParseError> {
+        let header = match TgaHeader::from_bytes(data) {
+            Some(header) => header,
+            None => return Err(ParseError::Header),
+        };
+
+        // The image ID is stored between the header and the color map.
+        let color_map_start = HEADER_LENGTH + header.id_len as usize;
+        if data.len() < color_map_start {
+            return Err(ParseError::Header);
+        }
+        let (_, input) = data.split_at(color_map_start);
 
-        let footer_length = TgaFooter::parse(data).map_or(0, |footer| footer.length(data));
+        let (input, color_map) = match ColorMap::parse(input, &header) {
+            Ok(color_map) => color_map,
+            Err(error) => return Err(error),
+        };
+
+        let footer_length = match TgaFooter::parse(data) {
+            Some(footer) => footer.length(data),
+            None => 0,
+        };
 
         // Use saturating_sub to make sure this can't panic
-        let pixel_data = &input[0..input.len().saturating_sub(footer_length)];
+        let (pixel_data, _) = input.split_at(input.len().saturating_sub(footer_length));
 
-        let size = Size::new(u32::from(header.width), u32::from(header.height));
+        let size = Size::new(header.width as u32, header.height as u32);
 
         Ok(Self {
             data,
@@ -70,9 +95,41 @@ impl<'a> RawTga<'a> {
             image_origin: header.image_origin,
             data_type: header.data_type,
             compression: header.compression,
+            is_postage_stamp: false,
         })
     }
 
+    /// Checks if a byte slice contains a supported TGA image.
+    ///
+    /// Returns the native color type of the image if the data can be parsed by
+    /// [`Tga::from_slice`], otherwise the same error that would be returned by `Tga::from_slice`.
+    /// The image data itself isn't checked.
+    ///
+    /// This method is a `const fn`, which makes it possible to check images at compile time. See
+    /// the [`include_tga`] macro for a convenient way to include and check images.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tinytga::{ColorType, RawTga};
+    ///
+    /// const COLOR_TYPE: ColorType = match RawTga::check(include_bytes!("../tests/type3_tl.tga")) {
+    ///     Ok(color_type) => color_type,
+    ///     Err(_) => panic!("invalid image"),
+    /// };
+    ///
+    /// assert_eq!(COLOR_TYPE, ColorType::Gray8);
+    /// ```
+    ///
+    /// [`Tga::from_slice`]: struct.Tga.html#method.from_slice
+    /// [`include_tga`]: macro.include_tga.html
+    pub const fn check(data: &[u8]) -> Result<ColorType, ParseError> {
+        match RawTga::from_slice(data) {
+            Ok(raw) => raw.color_type(),
+            Err(error) => Err(error),
+        }
+    }
+
     /// Returns the dimensions of this image.
     pub fn size(&self) -> Size {
         self.size
@@ -92,7 +149,7 @@ impl<'a> RawTga<'a> {
     /// pixel in the image data.
     ///
     /// [`image_data_bpp`]: #method.image_data_bpp
-    pub fn color_bpp(&self) -> Bpp {
+    pub const fn color_bpp(&self) -> Bpp {
         if let Some(color_map) = &self.color_map {
             color_map.entry_bpp()
         } else {
@@ -110,6 +167,13 @@ impl<'a> RawTga<'a> {
         self.data_type
     }
 
+    /// Returns the native color type of this image.
+    ///
+    /// An error is returned if the combination of data type and bit depth isn't supported.
+    pub const fn color_type(&self) -> Result<ColorType, ParseError> {
+        ColorType::new(self.data_type, self.color_bpp(), self.bpp)
+    }
+
     /// Returns the compression type.
     pub fn compression(&self) -> Compression {
         self.compression
@@ -129,7 +193,8 @@ impl<'a> RawTga<'a> {
     ///
     /// For color mapped images, where the image data consists of color indices, the returned value
     /// describes the bit depth of the indices and may differ from the depth returned by
-    /// [`color_bpp`].
+    /// [`color_bpp`]. Color indices are stored as 8 bit values, or as 16 bit values for color maps
+    /// with more than 256 entries.
     ///
     /// [`color_bpp`]: #method.color_bpp
     pub fn image_data_bpp(&self) -> Bpp {
@@ -141,6 +206,167 @@ impl<'a> RawTga<'a> {
         RawPixels::new(self)
     }
 
+    /// Returns a row iterator over the raw pixel values in this image.
+    ///
+    /// The rows are returned in top to bottom display order and the values in each row from left
+    /// to right, regardless of the image origin.
+    ///
+    /// # Performance
+    ///
+    /// RLE compressed images with the origin at the bottom, which don't contain a [scan line
+    /// table], need to decode all previous rows in the image data for every returned row. To save
+    /// memory the footer and extension area are parsed once when this method is called.
+    ///
+    /// [scan line table]: #method.scan_line_table
+    pub fn scanlines(&self) -> RawScanlines<'_> {
+        RawScanlines::new(self)
+    }
+
+    /// Decodes the raw pixel values into a buffer.
+    ///
+    /// The values are stored in row-major order, starting at the top left corner of the image,
+    /// regardless of the image origin. For color mapped images the values are the color indices.
+    ///
+    /// The length of `buffer` must be equal to the number of pixels in the image, otherwise
+    /// [`ParseError::MismatchedLength`] is returned.
+    ///
+    /// [`ParseError::MismatchedLength`]: enum.ParseError.html#variant.MismatchedLength
+    pub fn decode_into(&self, buffer: &mut [u32]) -> Result<(), ParseError> {
+        self.decode_with(buffer.len(), 1, |index, raw| buffer[index] = raw)
+    }
+
+    /// Decodes the raw pixel values into a byte buffer.
+    ///
+    /// The values are stored in the same order as by [`decode_into`]. Each value is stored in
+    /// little endian byte order and uses the number of bytes returned by [`image_data_bpp`].
+    ///
+    /// The length of `buffer` must be equal to the number of pixels in the image multiplied by
+    /// the number of bytes per pixel, otherwise [`ParseError::MismatchedLength`] is returned.
+    ///
+    /// [`decode_into`]: #method.decode_into
+    /// [`image_data_bpp`]: #method.image_data_bpp
+    /// [`ParseError::MismatchedLength`]: enum.ParseError.html#variant.MismatchedLength
+    pub fn decode_into_bytes(&self, buffer: &mut [u8]) -> Result<(), ParseError> {
+        let bytes = usize::from(self.bpp.bytes());
+
+        self.decode_with(buffer.len(), bytes, |index, raw| {
+            let start = index * bytes;
+            buffer[start..start + bytes].copy_from_slice(&raw.to_le_bytes()[0..bytes]);
+        })
+    }
+
+    /// Decodes the raw pixel values and passes them to `f`, together with the index of the pixel
+    /// in row-major order.
+    ///
+    /// `length` is the length of the output buffer, which is checked against the number of pixels
+    /// multiplied by `values_per_pixel`.
+    pub(crate) fn decode_with<F>(
+        &self,
+        length: usize,
+        values_per_pixel: usize,
+        mut f: F,
+    ) -> Result<(), ParseError>
+    where
+        F: FnMut(usize, u32),
+    {
+        let width = self.size.width as usize;
+        let expected = width * self.size.height as usize * values_per_pixel;
+        if length != expected {
+            return Err(ParseError::MismatchedLength {
+                expected,
+                actual: length,
+            });
+        }
+
+        // The X coordinates returned by `pixels` are always in the order the pixels are stored in
+        // the image data.
+        let mirrored = self.image_origin.is_right();
+
+        for pixel in self.pixels() {
+            let x = pixel.position.x as usize;
+            let x = if mirrored { width - 1 - x } else { x };
+
+            f(pixel.position.y as usize * width + x, pixel.color);
+        }
+
+        Ok(())
+    }
+
+    /// Returns an iterator over the raw colors in a single row.
+    ///
+    /// The row is selected by the `y` coordinate relative to the top of the image, regardless of
+    /// the image origin. The colors are returned in the order they are stored in the image data,
+    /// i.e. from right to left for images with the origin on the right side.
+    ///
+    /// `None` is returned if `y` is outside the image.
+    ///
+    /// # Performance
+    ///
+    /// Rows in uncompressed images and RLE compressed images with a [scan line table] are
+    /// accessed directly. For RLE compressed images without a scan line table all previous rows
+    /// need to be decoded. To save memory the footer and extension area are parsed every time
+    /// this method is called.
+    ///
+    /// [scan line table]: #method.scan_line_table
+    pub fn row(&self, y: u32) -> Option<RawRow<'a>> {
+        let scan_line_table = match self.compression {
+            Compression::Uncompressed => None,
+            Compression::Rle => self.scan_line_table(),
+        };
+
+        self.row_with_table(y, scan_line_table.as_ref())
+    }
+
+    /// Returns an iterator over the raw colors in a single row using a preparsed scan line table.
+    pub(crate) fn row_with_table(
+        &self,
+        y: u32,
+        scan_line_table: Option<&ScanLineTable<'a>>,
+    ) -> Option<RawRow<'a>> {
+        if y >= self.size.height {
+            return None;
+        }
+
+        // Index of the row in the image data.
+        let index = if self.image_origin.is_bottom() {
+            self.size.height - 1 - y
+        } else {
+            y
+        };
+
+        Some(match self.compression {
+            Compression::Uncompressed => {
+                let row_length = self.size.width as usize * usize::from(self.bpp.bytes());
+                let start = index as usize * row_length;
+
+                RawRow::new(self, self.pixel_data.get(start..).unwrap_or(&[]))
+            }
+            Compression::Rle => {
+                let row_data = scan_line_table
+                    .and_then(|table| table.get(index as usize))
+                    .and_then(|offset| self.data.get(offset..));
+
+                match row_data {
+                    Some(row_data) => RawRow::new(self, row_data),
+                    None => RawRow::skip_rows(self, index),
+                }
+            }
+        })
+    }
+
+    /// Returns the scan line table.
+    ///
+    /// `None` is returned if the image contains no scan line table or if the table is truncated.
+    ///
+    /// # Performance
+    ///
+    /// To save memory the footer and extension area are parsed every time this method is called.
+    pub fn scan_line_table(&self) -> Option<ScanLineTable<'a>> {
+        let offset = self.extension()?.scan_line_offset?;
+
+        ScanLineTable::parse(self.data.get(offset..)?, self.size.height)
+    }
+
     /// Returns the TGA header.
     ///
     /// The returned object is a direct representation of the header contained
@@ -162,18 +388,96 @@ impl<'a> RawTga<'a> {
     ///
     /// To save memory the footer is parsed every time this method is called.
     pub fn developer_directory(&self) -> Option<&'a [u8]> {
+        if self.is_postage_stamp {
+            return None;
+        }
+
         TgaFooter::parse(self.data).and_then(|footer| footer.developer_directory(self.data))
     }
 
+    /// Returns an iterator over the entries in the developer directory.
+    ///
+    /// The iterator is empty if the image contains no developer directory.
+    ///
+    /// # Performance
+    ///
+    /// To save memory the footer is parsed every time this method is called.
+    pub fn developer_tags(&self) -> DeveloperTags<'a> {
+        DeveloperTags::new(self.data, self.developer_directory())
+    }
+
     /// Returns the extension area.
     ///
     /// # Performance
     ///
     /// To save memory the footer is parsed every time this method is called.
     pub fn extension_area(&self) -> Option<&'a [u8]> {
+        if self.is_postage_stamp {
+            return None;
+        }
+
         TgaFooter::parse(self.data).and_then(|footer| footer.extension_area(self.data))
     }
 
+    /// Returns the parsed extension area.
+    ///
+    /// `None` is returned if the image contains no extension area or if the extension area is
+    /// invalid.
+    ///
+    /// # Performance
+    ///
+    /// To save memory the footer and extension area are parsed every time this method is called.
+    pub fn extension(&self) -> Option<TgaExtension<'a>> {
+        if self.is_postage_stamp {
+            return None;
+        }
+
+        // The extension area has a fixed length and is parsed directly at its offset, up to the
+        // start of the footer.
+        let offset = TgaFooter::parse(self.data)?.extension_area_offset()?;
+        let footer_start = self.data.len() - TGA_FOOTER_LENGTH;
+
+        TgaExtension::parse(self.data.get(offset..footer_start)?)
+    }
+
+    /// Returns the postage stamp image.
+    ///
+    /// The postage stamp is a small, uncompressed version of the image, which can be used as a
+    /// thumbnail. It uses the same pixel format, color map and image origin as the main image.
+    /// `None` is returned if the image contains no postage stamp or if the postage stamp is
+    /// truncated.
+    ///
+    /// Only the methods which return information about the image data, like [`size`] and
+    /// [`pixels`], refer to the postage stamp. The postage stamp has no extension area, scan line
+    /// table, developer directory or postage stamp of its own. All other methods, like
+    /// [`header`] and [`image_id`], still return information about the main image.
+    ///
+    /// # Performance
+    ///
+    /// To save memory the footer and extension area are parsed every time this method is called.
+    ///
+    /// [`size`]: #method.size
+    /// [`pixels`]: #method.pixels
+    /// [`header`]: #method.header
+    /// [`image_id`]: #method.image_id
+    pub fn postage_stamp(&self) -> Option<RawTga<'a>> {
+        let offset = self.extension()?.postage_stamp_offset?;
+
+        let (input, (width, height)) = parse_postage_stamp_size(self.data.get(offset..)?).ok()?;
+        let size = Size::new(u32::from(width), u32::from(height));
+
+        let length = size.width as usize * size.height as usize * usize::from(self.bpp.bytes());
+        let pixel_data = input.get(..length)?;
+
+        Some(Self {
+            pixel_data,
+            size,
+            compression: Compression::Uncompressed,
+            is_postage_stamp: true,
+            ..*self
+        })
+    }
+
     /// Returns the content of the image ID.
     ///
     /// If the TGA file doesn't contain an image ID `None` is returned.
@@ -191,6 +495,10 @@ impl<'a> RawTga<'a> {
     }
 }
 
+fn parse_postage_stamp_size(input: &[u8]) -> IResult<&[u8], (u8, u8)> {
+    pair(le_u8, le_u8)(input)
+}
+
 fn parse_image_id<'a>(input: &'a [u8], header: &TgaHeader) -> IResult<&'a [u8], &'a [u8]> {
     take(header.id_len)(input)
 }
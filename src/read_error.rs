@@ -0,0 +1,55 @@
+use embedded_io::ReadExactError;
+
+use crate::parse_error::ParseError;
+
+/// Possible errors when reading a TGA image from a reader
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum ReadError<E> {
+    /// The reader returned an error.
+    Read(E),
+
+    /// The end of the input was reached before the image was completely read.
+    UnexpectedEof,
+
+    /// An error occurred when parsing the image.
+    Parse(ParseError),
+
+    /// A buffer passed to the reader is too small.
+    BufferTooSmall {
+        /// Required buffer length.
+        required: usize,
+    },
+}
+
+impl<E> From<ReadExactError<E>> for ReadError<E> {
+    fn from(error: ReadExactError<E>) -> Self {
+        match error {
+            ReadExactError::UnexpectedEof => Self::UnexpectedEof,
+            ReadExactError::Other(error) => Self::Read(error),
+        }
+    }
+}
+
+impl<E> From<ParseError> for ReadError<E> {
+    fn from(error: ParseError) -> Self {
+        Self::Parse(error)
+    }
+}
+
+/// Errors returned when a TGA image is read from a reader and drawn to a draw target
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[non_exhaustive]
+pub enum ReadDrawError<R, D> {
+    /// The image couldn't be read.
+    Read(ReadError<R>),
+
+    /// The draw target returned an error.
+    DrawTarget(D),
+}
+
+impl<R, D> From<ReadError<R>> for ReadDrawError<R, D> {
+    fn from(error: ReadError<R>) -> Self {
+        Self::Read(error)
+    }
+}
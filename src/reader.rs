@@ -0,0 +1,270 @@
+use embedded_graphics::{
+    pixelcolor::{Gray8, Rgb555, Rgb888},
+    prelude::*,
+};
+use embedded_io::{Read, Seek, SeekFrom};
+
+use crate::{
+    color_map::ColorMap,
+    extension::{TgaExtension, EXTENSION_AREA_LENGTH},
+    footer::{TgaFooter, TGA_FOOTER_LENGTH},
+    header::{TgaHeader, HEADER_LENGTH},
+    raw_iter::RawPixel,
+    read_error::ReadError,
+    stream_decoder::{Decode, StreamDecoder, StreamSetup},
+};
+
+/// Streaming TGA decoder.
+///
+/// `TgaReader` decodes a TGA image from an [`embedded_io::Read`] source without loading the
+/// entire file into memory. Only the color map is stored in a caller provided buffer, all other
+/// data is read through a small fixed size buffer.
+///
+/// The pixels can be read one at a time by using the reader as an iterator, which yields
+/// [`RawPixel`]s, or one row at a time by using the [`read_row`] and [`read_row_colors`] methods.
+/// The pixels and rows are returned in the order they are stored in the file, but their positions
+/// are always relative to the top left corner of the image.
+///
+/// The iterator returns the same colors in the same order as [`RawTga::pixels`]. Unlike
+/// `RawTga::pixels`, which returns the X coordinates in the order the pixels are stored in the
+/// image data, the X coordinates of images with the origin on the right side are mirrored.
+///
+/// Decoding errors can't be recovered from and the reader shouldn't be used after an error was
+/// returned.
+///
+/// This type requires the `embedded-io` feature.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::pixelcolor::Rgb888;
+/// use tinytga::TgaReader;
+///
+/// let data = include_bytes!("../tests/chessboard_4px_rle.tga");
+///
+/// let mut reader = TgaReader::new(&data[..], &mut []).unwrap();
+/// assert_eq!(reader.header().width, 4);
+///
+/// let mut row = [Rgb888::default(); 4];
+/// while let Some(y) = reader.read_row_colors(&mut row).unwrap() {
+///     // Process the row at position `y`.
+/// #   assert!(y < 4);
+/// }
+/// ```
+///
+/// [`RawPixel`]: struct.RawPixel.html
+/// [`RawTga::pixels`]: struct.RawTga.html#method.pixels
+/// [`read_row`]: #method.read_row
+/// [`read_row_colors`]: #method.read_row_colors
+#[derive(Debug)]
+pub struct TgaReader<'b, R> {
+    /// Underlying reader.
+    reader: R,
+
+    /// Image data decoder.
+    decoder: StreamDecoder<'b>,
+}
+
+impl<'b, R> TgaReader<'b, R>
+where
+    R: Read,
+{
+    /// Creates a new reader.
+    ///
+    /// The header, image ID and color map are read immediately. The color map is stored in
+    /// `color_map_buffer`, which must be large enough to hold the color map. Images without a
+    /// color map can use an empty buffer.
+    pub fn new(mut reader: R, color_map_buffer: &'b mut [u8]) -> Result<Self, ReadError<R::Error>> {
+        let mut header = [0; HEADER_LENGTH];
+        reader.read_exact(&mut header)?;
+        let mut setup = StreamSetup::new(&header)?;
+
+        // Skip the image ID.
+        while let Some(buffer) = setup.image_id_buffer() {
+            reader.read_exact(buffer)?;
+        }
+
+        let color_map_data = setup.color_map_buffer(color_map_buffer)?;
+        reader.read_exact(color_map_data)?;
+
+        Ok(Self {
+            reader,
+            decoder: setup.into_decoder(color_map_data)?,
+        })
+    }
+
+    /// Returns the TGA header.
+    pub fn header(&self) -> TgaHeader {
+        self.decoder.header()
+    }
+
+    /// Returns the dimensions of this image.
+    pub fn size(&self) -> Size {
+        self.decoder.size()
+    }
+
+    /// Returns the color map.
+    ///
+    /// `None` is returned if the image contains no color map.
+    pub fn color_map(&self) -> Option<&ColorMap<'b>> {
+        self.decoder.color_map()
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Reads the next row of raw pixel values.
+    ///
+    /// The values are stored in `row` from left to right, starting at index `0`. For color mapped
+    /// images the values are the color indices. Returns the Y coordinate of the row or `None` if
+    /// all rows have been read.
+    ///
+    /// `row` must be at least as long as the image width, otherwise
+    /// [`ReadError::BufferTooSmall`] is returned.
+    ///
+    /// [`ReadError::BufferTooSmall`]: enum.ReadError.html#variant.BufferTooSmall
+    pub fn read_row(&mut self, row: &mut [u32]) -> Result<Option<u32>, ReadError<R::Error>> {
+        self.read_row_with(row.len(), |_, x, raw| {
+            row[x] = raw;
+            Ok(())
+        })
+    }
+
+    /// Reads the next row of colors.
+    ///
+    /// The colors are stored in `row` from left to right, starting at index `0`. Returns the Y
+    /// coordinate of the row or `None` if all rows have been read. The alpha channel of 32 bit
+    /// colors is ignored.
+    ///
+    /// `row` must be at least as long as the image width, otherwise
+    /// [`ReadError::BufferTooSmall`] is returned. Color indices outside the range of the color
+    /// map are returned as [`ParseError::InvalidColorIndex`] errors.
+    ///
+    /// [`ReadError::BufferTooSmall`]: enum.ReadError.html#variant.BufferTooSmall
+    /// [`ParseError::InvalidColorIndex`]: enum.ParseError.html#variant.InvalidColorIndex
+    pub fn read_row_colors<C>(&mut self, row: &mut [C]) -> Result<Option<u32>, ReadError<R::Error>>
+    where
+        C: PixelColor + From<Gray8> + From<Rgb555> + From<Rgb888>,
+    {
+        self.read_row_with(row.len(), |decoder, x, raw| {
+            row[x] = decoder.convert(raw)?;
+            Ok(())
+        })
+    }
+
+    /// Reads the remaining pixels in the current row and passes them to `f`.
+    fn read_row_with<F>(
+        &mut self,
+        length: usize,
+        mut f: F,
+    ) -> Result<Option<u32>, ReadError<R::Error>>
+    where
+        F: FnMut(&StreamDecoder<'b>, usize, u32) -> Result<(), ReadError<R::Error>>,
+    {
+        self.decoder.check_row_length(length)?;
+
+        loop {
+            match self.decoder.decode_row(&mut f)? {
+                Decode::Complete(y) => return Ok(y),
+                Decode::NeedData => self.fill_buffer()?,
+            }
+        }
+    }
+
+    /// Reads the next chunk of data into the decoder's buffer.
+    fn fill_buffer(&mut self) -> Result<(), ReadError<R::Error>> {
+        let result = self.reader.read(self.decoder.buffer_mut());
+
+        self.decoder.filled(result)
+    }
+}
+
+impl<R> TgaReader<'_, R>
+where
+    R: Read + Seek,
+{
+    /// Reads the extension area.
+    ///
+    /// The footer is read from the end of the input to locate the extension area, which is then
+    /// stored in `buffer`. `buffer` must be at least 495 bytes long. `None` is returned if the
+    /// image doesn't contain a valid footer or extension area.
+    ///
+    /// The position of the underlying reader is restored afterwards, which makes it possible to
+    /// call this method before, while or after the image data is read.
+    pub fn read_extension<'e>(
+        &mut self,
+        buffer: &'e mut [u8],
+    ) -> Result<Option<TgaExtension<'e>>, ReadError<R::Error>> {
+        let buffer = buffer
+            .get_mut(0..EXTENSION_AREA_LENGTH)
+            .ok_or(ReadError::BufferTooSmall {
+                required: EXTENSION_AREA_LENGTH,
+            })?;
+
+        let position = self.reader.stream_position().map_err(ReadError::Read)?;
+        let found = self.read_extension_area(buffer);
+        self.reader
+            .seek(SeekFrom::Start(position))
+            .map_err(ReadError::Read)?;
+
+        Ok(if found? {
+            TgaExtension::parse(buffer)
+        } else {
+            None
+        })
+    }
+
+    /// Reads the extension area into `buffer`.
+    ///
+    /// Returns `false` if the file doesn't contain an extension area.
+    fn read_extension_area(&mut self, buffer: &mut [u8]) -> Result<bool, ReadError<R::Error>> {
+        let length = self
+            .reader
+            .seek(SeekFrom::End(0))
+            .map_err(ReadError::Read)?;
+        if length < TGA_FOOTER_LENGTH as u64 {
+            return Ok(false);
+        }
+
+        let mut footer = [0; TGA_FOOTER_LENGTH];
+        self.reader
+            .seek(SeekFrom::End(-(TGA_FOOTER_LENGTH as i64)))
+            .map_err(ReadError::Read)?;
+        self.reader.read_exact(&mut footer)?;
+
+        let offset =
+            match TgaFooter::parse(&footer).and_then(|footer| footer.extension_area_offset()) {
+                Some(offset) => offset,
+                None => return Ok(false),
+            };
+
+        self.reader
+            .seek(SeekFrom::Start(offset as u64))
+            .map_err(ReadError::Read)?;
+        self.reader.read_exact(buffer)?;
+
+        Ok(true)
+    }
+}
+
+impl<R> Iterator for TgaReader<'_, R>
+where
+    R: Read,
+{
+    type Item = Result<RawPixel, ReadError<R::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.decoder.next_pixel() {
+                Decode::Complete(pixel) => return pixel.map(Ok),
+                Decode::NeedData => {
+                    if let Err(error) = self.fill_buffer() {
+                        return Some(Err(error));
+                    }
+                }
+            }
+        }
+    }
+}
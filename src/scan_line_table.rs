@@ -0,0 +1,44 @@
+/// Scan line table.
+///
+/// The scan line table is an optional part of the TGA 2.0 extension area. It contains the offset
+/// of each row in the image data, which makes it possible to access individual rows of RLE
+/// compressed images without decoding all previous rows.
+///
+/// The rows are stored in the same order as in the image data, e.g. the first entry refers to
+/// the bottom row for images with the origin at the bottom of the image.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct ScanLineTable<'a> {
+    /// Table data.
+    data: &'a [u8],
+}
+
+impl<'a> ScanLineTable<'a> {
+    /// Parses a scan line table with `rows` entries.
+    ///
+    /// Returns `None` if the input is too short.
+    pub(crate) fn parse(input: &'a [u8], rows: u32) -> Option<Self> {
+        let length = usize::try_from(rows).ok()?.checked_mul(4)?;
+
+        input.get(..length).map(|data| Self { data })
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.data.len() / 4
+    }
+
+    /// Returns `true` if the table contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the offset of a row from the start of the file.
+    ///
+    /// `None` is returned if the index is outside the table.
+    pub fn get(&self, index: usize) -> Option<usize> {
+        let start = index.checked_mul(4)?;
+        let bytes = self.data.get(start..start.checked_add(4)?)?;
+
+        Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize)
+    }
+}
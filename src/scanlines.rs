@@ -0,0 +1,166 @@
+use embedded_graphics::{
+    pixelcolor::{Gray8, Rgb555, Rgb888},
+    prelude::*,
+};
+
+use crate::{
+    parse_error::ParseError, raw_iter::RawRow, scan_line_table::ScanLineTable, Compression, RawTga,
+    Tga,
+};
+
+/// Row iterator over the raw pixel values.
+///
+/// The rows are returned in top to bottom display order, regardless of the image origin, by
+/// calling [`next_row`]. See the [`scanlines`] method for additional information.
+///
+/// [`next_row`]: #method.next_row
+/// [`scanlines`]: struct.RawTga.html#method.scanlines
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct RawScanlines<'a> {
+    raw_tga: &'a RawTga<'a>,
+
+    /// Scan line table for RLE compressed images.
+    scan_line_table: Option<ScanLineTable<'a>>,
+
+    /// Image data decoder for RLE compressed images without a scan line table, which store the
+    /// rows in display order.
+    sequential: Option<RawRow<'a>>,
+
+    /// Y coordinate of the next row.
+    y: u32,
+}
+
+impl<'a> RawScanlines<'a> {
+    pub(crate) fn new(raw_tga: &'a RawTga<'a>) -> Self {
+        let scan_line_table = match raw_tga.compression() {
+            Compression::Uncompressed => None,
+            Compression::Rle => raw_tga.scan_line_table(),
+        };
+
+        let sequential = if raw_tga.compression() == Compression::Rle
+            && scan_line_table.is_none()
+            && !raw_tga.image_origin().is_bottom()
+        {
+            Some(RawRow::skip_rows(raw_tga, 0))
+        } else {
+            None
+        };
+
+        Self {
+            raw_tga,
+            scan_line_table,
+            sequential,
+            y: 0,
+        }
+    }
+
+    /// Decodes the next row into a buffer.
+    ///
+    /// The raw values are stored in `row` from left to right. For color mapped images the values
+    /// are the color indices. Returns the Y coordinate of the row or `None` if all rows have been
+    /// returned.
+    ///
+    /// The length of `row` must be equal to the image width, otherwise
+    /// [`ParseError::MismatchedLength`] is returned.
+    ///
+    /// [`ParseError::MismatchedLength`]: enum.ParseError.html#variant.MismatchedLength
+    pub fn next_row(&mut self, row: &mut [u32]) -> Result<Option<u32>, ParseError> {
+        self.next_row_with(row.len(), |x, raw| row[x] = raw)
+    }
+
+    /// Decodes the next row and passes the raw values to `f`, together with their X coordinate.
+    pub(crate) fn next_row_with<F>(
+        &mut self,
+        length: usize,
+        mut f: F,
+    ) -> Result<Option<u32>, ParseError>
+    where
+        F: FnMut(usize, u32),
+    {
+        let size = self.raw_tga.size();
+        let width = size.width as usize;
+        if length != width {
+            return Err(ParseError::MismatchedLength {
+                expected: width,
+                actual: length,
+            });
+        }
+
+        if self.y >= size.height {
+            return Ok(None);
+        }
+        let y = self.y;
+        self.y += 1;
+
+        // The pixels in each row are stored from right to left.
+        let mirrored = self.raw_tga.image_origin().is_right();
+
+        if let Some(sequential) = &mut self.sequential {
+            fill_row(&mut *sequential, width, mirrored, &mut f);
+            sequential.continue_with_next_row(size.width);
+        } else if let Some(values) = self
+            .raw_tga
+            .row_with_table(y, self.scan_line_table.as_ref())
+        {
+            fill_row(values, width, mirrored, &mut f);
+        }
+
+        Ok(Some(y))
+    }
+}
+
+/// Passes the values in a row to `f`, together with their X coordinate.
+fn fill_row<I, F>(values: I, width: usize, mirrored: bool, f: &mut F)
+where
+    I: Iterator<Item = u32>,
+    F: FnMut(usize, u32),
+{
+    // Missing values in truncated images are replaced by `0`.
+    for (index, raw) in values.chain(core::iter::repeat(0)).take(width).enumerate() {
+        let x = if mirrored { width - 1 - index } else { index };
+        f(x, raw);
+    }
+}
+
+/// Row iterator over the pixel colors.
+///
+/// The rows are returned in top to bottom display order, regardless of the image origin, by
+/// calling [`next_row`]. See the [`scanlines`] method for additional information.
+///
+/// [`next_row`]: #method.next_row
+/// [`scanlines`]: struct.Tga.html#method.scanlines
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Scanlines<'a, C> {
+    tga: &'a Tga<'a, C>,
+    raw: RawScanlines<'a>,
+}
+
+impl<'a, C> Scanlines<'a, C>
+where
+    C: PixelColor + From<Gray8> + From<Rgb555> + From<Rgb888>,
+{
+    pub(crate) fn new(tga: &'a Tga<'a, C>) -> Self {
+        Self {
+            tga,
+            raw: RawScanlines::new(tga.as_raw()),
+        }
+    }
+
+    /// Decodes the next row into a buffer.
+    ///
+    /// The colors are stored in `row` from left to right. Color indices outside the range of the
+    /// color map are replaced by the [fallback color]. Returns the Y coordinate of the row or
+    /// `None` if all rows have been returned.
+    ///
+    /// The length of `row` must be equal to the image width, otherwise
+    /// [`ParseError::MismatchedLength`] is returned.
+    ///
+    /// [fallback color]: struct.Tga.html#method.with_fallback_color
+    /// [`ParseError::MismatchedLength`]: enum.ParseError.html#variant.MismatchedLength
+    pub fn next_row(&mut self, row: &mut [C]) -> Result<Option<u32>, ParseError> {
+        let tga = self.tga;
+
+        self.raw
+            .next_row_with(row.len(), |x, raw| row[x] = tga.convert_raw(raw, 0).0)
+    }
+}
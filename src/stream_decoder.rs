@@ -0,0 +1,366 @@
+use embedded_graphics::{
+    pixelcolor::{Gray8, Rgb555, Rgb888},
+    prelude::*,
+};
+
+use crate::{
+    color_map::ColorMap,
+    header::{TgaHeader, HEADER_LENGTH},
+    parse_error::ParseError,
+    raw_iter::RawPixel,
+    read_error::ReadError,
+    ColorType, Compression,
+};
+
+/// Length of the internal read buffer in bytes
+const BUFFER_LENGTH: usize = 64;
+
+/// Result of a decoding step which uses the buffered data.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub(crate) enum Decode<T> {
+    /// The step was completed.
+    Complete(T),
+    /// More data needs to be read into the buffer returned by `StreamDecoder::buffer_mut`.
+    NeedData,
+}
+
+/// Header, image ID and color map setup used by the streaming readers.
+///
+/// The readers read the header, pass it to `new`, read the image ID into the buffers returned by
+/// `image_id_buffer`, read the color map into the buffer returned by `color_map_buffer` and
+/// finally create the decoder by calling `into_decoder`.
+#[derive(Debug)]
+pub(crate) struct StreamSetup {
+    /// Image header.
+    header: TgaHeader,
+
+    /// Number of bytes in the image ID that haven't been read yet.
+    image_id_remaining: usize,
+
+    /// Read buffer, which is passed on to the decoder.
+    buffer: [u8; BUFFER_LENGTH],
+}
+
+impl StreamSetup {
+    /// Parses the header.
+    pub fn new(header: &[u8; HEADER_LENGTH]) -> Result<Self, ParseError> {
+        let (_, header) = TgaHeader::parse(header).map_err(|_| ParseError::Header)?;
+
+        Ok(Self {
+            header,
+            image_id_remaining: usize::from(header.id_len),
+            buffer: [0; BUFFER_LENGTH],
+        })
+    }
+
+    /// Returns a buffer for the next part of the image ID.
+    ///
+    /// The image ID isn't stored and the returned buffer must be filled completely. Returns
+    /// `None` if the entire image ID has been read.
+    pub fn image_id_buffer(&mut self) -> Option<&mut [u8]> {
+        if self.image_id_remaining == 0 {
+            return None;
+        }
+
+        let length = self.image_id_remaining.min(BUFFER_LENGTH);
+        self.image_id_remaining -= length;
+
+        Some(&mut self.buffer[0..length])
+    }
+
+    /// Returns the part of `color_map_buffer` the color map needs to be read into.
+    ///
+    /// The returned buffer is empty if the image contains no color map.
+    pub fn color_map_buffer<'b, E>(
+        &self,
+        color_map_buffer: &'b mut [u8],
+    ) -> Result<&'b mut [u8], ReadError<E>> {
+        let required = if self.header.has_color_map {
+            let entry_bpp = self.header.color_map_depth.ok_or(ParseError::ColorMap)?;
+            usize::from(self.header.color_map_len) * usize::from(entry_bpp.bytes())
+        } else {
+            0
+        };
+
+        color_map_buffer
+            .get_mut(0..required)
+            .ok_or(ReadError::BufferTooSmall { required })
+    }
+
+    /// Creates the decoder.
+    ///
+    /// `color_map_data` must contain the color map if the image contains a color map.
+    pub fn into_decoder(self, color_map_data: &[u8]) -> Result<StreamDecoder<'_>, ParseError> {
+        let header = self.header;
+        let (_, color_map) = ColorMap::parse(color_map_data, &header)?;
+
+        let color_bpp = color_map.map_or(header.pixel_depth, |color_map| color_map.entry_bpp());
+        let color_type = ColorType::new(header.data_type, color_bpp, header.pixel_depth)?;
+
+        Ok(StreamDecoder {
+            header,
+            color_map,
+            color_type,
+            buffer: self.buffer,
+            buffer_start: 0,
+            buffer_end: 0,
+            packet_remaining: 0,
+            run: false,
+            value: [0; 4],
+            value_length: 0,
+            x: 0,
+            row: 0,
+        })
+    }
+}
+
+/// Image data decoder used by the streaming readers.
+///
+/// The decoder doesn't read any data itself. Instead the readers fill the buffer returned by
+/// `buffer_mut` whenever the decoder returns `Decode::NeedData`, which makes it possible to use
+/// the same decoder for blocking and async readers.
+#[derive(Debug)]
+pub(crate) struct StreamDecoder<'b> {
+    /// Image header.
+    header: TgaHeader,
+
+    /// Color map.
+    color_map: Option<ColorMap<'b>>,
+
+    /// Color type used to convert raw values into colors.
+    color_type: ColorType,
+
+    /// Read buffer.
+    buffer: [u8; BUFFER_LENGTH],
+
+    /// Index of the next unread byte in the buffer.
+    buffer_start: usize,
+
+    /// Number of valid bytes in the buffer.
+    buffer_end: usize,
+
+    /// Remaining pixels in the current RLE packet.
+    packet_remaining: u8,
+
+    /// Whether the current RLE packet is a run packet.
+    run: bool,
+
+    /// Bytes of the current raw value.
+    value: [u8; 4],
+
+    /// Number of bytes in `value`.
+    value_length: u8,
+
+    /// Index of the next pixel in the current row, in file order.
+    x: u32,
+
+    /// Index of the current row, in file order.
+    row: u32,
+}
+
+impl<'b> StreamDecoder<'b> {
+    pub fn header(&self) -> TgaHeader {
+        self.header
+    }
+
+    pub fn size(&self) -> Size {
+        Size::new(u32::from(self.header.width), u32::from(self.header.height))
+    }
+
+    pub fn color_map(&self) -> Option<&ColorMap<'b>> {
+        self.color_map.as_ref()
+    }
+
+    /// Returns the read buffer.
+    ///
+    /// The reader must pass the result of reading into the buffer to `filled`.
+    pub fn buffer_mut(&mut self) -> &mut [u8] {
+        &mut self.buffer
+    }
+
+    /// Sets the number of bytes that were read into the buffer.
+    ///
+    /// `result` is the result of the read call. Read errors and reaching the end of the input,
+    /// which is returned as `ReadError::UnexpectedEof`, stop the decoding process.
+    pub fn filled<E>(&mut self, result: Result<usize, E>) -> Result<(), ReadError<E>> {
+        let error = match result {
+            Ok(0) => ReadError::UnexpectedEof,
+            Ok(length) => {
+                self.buffer_start = 0;
+                self.buffer_end = length.min(BUFFER_LENGTH);
+
+                return Ok(());
+            }
+            Err(error) => ReadError::Read(error),
+        };
+
+        // Decoding errors can't be recovered from.
+        self.row = u32::from(self.header.height);
+
+        Err(error)
+    }
+
+    /// Decodes the next pixel.
+    ///
+    /// Returns `Decode::Complete(None)` if all pixels have been decoded.
+    pub fn next_pixel(&mut self) -> Decode<Option<RawPixel>> {
+        if self.is_finished() {
+            return Decode::Complete(None);
+        }
+
+        match self.next_raw() {
+            Some(raw) => Decode::Complete(Some(RawPixel::new(self.next_position(), raw))),
+            None => Decode::NeedData,
+        }
+    }
+
+    /// Decodes the remaining pixels in the current row and passes them to `f`.
+    ///
+    /// `f` is called with the X coordinate and the raw value of each pixel. Returns the Y
+    /// coordinate of the row once the row is complete or `Decode::Complete(None)` if all rows
+    /// have been decoded.
+    pub fn decode_row<F, E>(&mut self, mut f: F) -> Result<Decode<Option<u32>>, E>
+    where
+        F: FnMut(&Self, usize, u32) -> Result<(), E>,
+    {
+        while !self.is_finished() {
+            let raw = match self.next_raw() {
+                Some(raw) => raw,
+                None => return Ok(Decode::NeedData),
+            };
+
+            let position = self.next_position();
+            f(self, position.x as usize, raw)?;
+
+            if self.x == 0 {
+                return Ok(Decode::Complete(Some(position.y as u32)));
+            }
+        }
+
+        Ok(Decode::Complete(None))
+    }
+
+    /// Checks that a row buffer with the given length can hold an entire row.
+    pub fn check_row_length<E>(&self, length: usize) -> Result<(), ReadError<E>> {
+        let required = self.size().width as usize;
+        if length < required {
+            return Err(ReadError::BufferTooSmall { required });
+        }
+
+        Ok(())
+    }
+
+    /// Converts a raw pixel value into a color.
+    pub fn convert<C>(&self, mut raw: u32) -> Result<C, ParseError>
+    where
+        C: PixelColor + From<Gray8> + From<Rgb555> + From<Rgb888>,
+    {
+        if let Some(color_map) = &self.color_map {
+            raw = color_map
+                .get_raw(raw as usize)
+                .ok_or(ParseError::InvalidColorIndex(raw))?;
+        }
+
+        Ok(self.color_type.convert(raw))
+    }
+
+    /// Returns `true` if all pixels have been decoded.
+    fn is_finished(&self) -> bool {
+        self.row >= u32::from(self.header.height) || self.header.width == 0
+    }
+
+    /// Returns the position of the next pixel and advances the position.
+    fn next_position(&mut self) -> Point {
+        let size = self.size();
+
+        let x = if self.header.image_origin.is_right() {
+            size.width - 1 - self.x
+        } else {
+            self.x
+        };
+
+        let y = if self.header.image_origin.is_bottom() {
+            size.height - 1 - self.row
+        } else {
+            self.row
+        };
+
+        self.x += 1;
+        if self.x == size.width {
+            self.x = 0;
+            self.row += 1;
+        }
+
+        Point::new(x as i32, y as i32)
+    }
+
+    /// Decodes the next raw value from the buffered data.
+    ///
+    /// Returns `None` if more data needs to be read.
+    fn next_raw(&mut self) -> Option<u32> {
+        loop {
+            if let Some(raw) = self.pending() {
+                return Some(raw);
+            }
+
+            if self.buffer_start == self.buffer_end {
+                return None;
+            }
+
+            let byte = self.buffer[self.buffer_start];
+            self.buffer_start += 1;
+
+            if let Some(raw) = self.push(byte) {
+                return Some(raw);
+            }
+        }
+    }
+
+    /// Returns the next raw value if it can be decoded without reading more data.
+    ///
+    /// This is the case for all but the first pixel in a RLE run packet.
+    fn pending(&mut self) -> Option<u32> {
+        if self.run && self.packet_remaining > 0 && self.value_length == self.bytes_per_pixel() {
+            self.packet_remaining -= 1;
+
+            Some(u32::from_le_bytes(self.value))
+        } else {
+            None
+        }
+    }
+
+    /// Decodes the next byte of image data.
+    ///
+    /// Returns the next raw value if `byte` completed it.
+    fn push(&mut self, byte: u8) -> Option<u32> {
+        if self.header.compression == Compression::Rle && self.packet_remaining == 0 {
+            self.packet_remaining = (byte & 0x7F) + 1;
+            self.run = byte & 0x80 != 0;
+            self.value = [0; 4];
+            self.value_length = 0;
+
+            return None;
+        }
+
+        self.value[usize::from(self.value_length)] = byte;
+        self.value_length += 1;
+
+        if self.value_length < self.bytes_per_pixel() {
+            return None;
+        }
+
+        let value = u32::from_le_bytes(self.value);
+
+        self.packet_remaining = self.packet_remaining.saturating_sub(1);
+        if !self.run {
+            self.value = [0; 4];
+            self.value_length = 0;
+        }
+
+        Some(value)
+    }
+
+    fn bytes_per_pixel(&self) -> u8 {
+        self.header.pixel_depth.bytes()
+    }
+}
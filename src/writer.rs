@@ -0,0 +1,159 @@
+use embedded_graphics::{image::GetPixel, prelude::*, primitives::Rectangle};
+
+use crate::{
+    encode_error::EncodeError,
+    encoder::{EncoderColor, TgaEncoder},
+};
+
+/// Draw target that records the drawn pixels to save them as a TGA image.
+///
+/// `TgaWriter` stores the drawn colors in a caller provided buffer, which can then be encoded
+/// by using a [`TgaEncoder`]. This makes it possible to compare the output of embedded-graphics
+/// drawing operations with reference TGA files.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_graphics::{
+///     pixelcolor::Rgb888,
+///     prelude::*,
+///     primitives::{Circle, PrimitiveStyle},
+/// };
+/// use tinytga::{Compression, Tga, TgaWriter};
+///
+/// let mut buffer = [Rgb888::BLACK; 16 * 16];
+/// let mut writer = TgaWriter::new(&mut buffer, Size::new(16, 16)).unwrap();
+///
+/// Circle::new(Point::new(2, 2), 12)
+///     .into_styled(PrimitiveStyle::with_fill(Rgb888::RED))
+///     .draw(&mut writer)?;
+///
+/// let mut data = Vec::new();
+/// writer
+///     .encoder()
+///     .with_compression(Compression::Rle)
+///     .encode(writer.colors(), &mut data)
+///     .unwrap();
+///
+/// let tga = Tga::<Rgb888>::from_slice(&data).unwrap();
+/// assert_eq!(tga.size(), Size::new(16, 16));
+/// # Ok::<(), core::convert::Infallible>(())
+/// ```
+///
+/// [`TgaEncoder`]: struct.TgaEncoder.html
+#[derive(PartialEq, Eq, Ord, PartialOrd, Hash, Debug)]
+pub struct TgaWriter<'a, C> {
+    /// Colors in row-major order.
+    buffer: &'a mut [C],
+
+    /// Image size.
+    size: Size,
+}
+
+impl<'a, C> TgaWriter<'a, C>
+where
+    C: PixelColor + EncoderColor,
+{
+    /// Creates a new TGA writer.
+    ///
+    /// The length of `buffer` must be equal to the number of pixels in the image. The initial
+    /// content of the buffer is used as the background of the image.
+    pub fn new(buffer: &'a mut [C], size: Size) -> Result<Self, EncodeError> {
+        let expected = size.width as usize * size.height as usize;
+        if buffer.len() != expected {
+            return Err(EncodeError::MismatchedLength {
+                expected,
+                actual: buffer.len(),
+            });
+        }
+
+        Ok(Self { buffer, size })
+    }
+
+    /// Returns the recorded colors.
+    ///
+    /// The colors are stored in row-major order, starting at the top left corner of the image.
+    pub fn colors(&self) -> &[C] {
+        self.buffer
+    }
+
+    /// Returns an encoder for the recorded image.
+    ///
+    /// The returned encoder uses the default settings and can be customized before the recorded
+    /// [`colors`] are encoded.
+    ///
+    /// [`colors`]: #method.colors
+    pub fn encoder(&self) -> TgaEncoder<'_, C> {
+        TgaEncoder::new(self.size)
+    }
+
+    /// Encodes the recorded image with the default encoder settings.
+    ///
+    /// The encoded TGA file is appended to `output`.
+    pub fn encode<E>(&self, output: &mut E) -> Result<(), EncodeError>
+    where
+        E: Extend<u8>,
+    {
+        self.encoder().encode(self.buffer, output)
+    }
+
+    /// Returns the index of a point in the buffer.
+    fn index(&self, point: Point) -> Option<usize> {
+        if self.bounding_box().contains(point) {
+            Some(point.y as usize * self.size.width as usize + point.x as usize)
+        } else {
+            None
+        }
+    }
+}
+
+impl<C> OriginDimensions for TgaWriter<'_, C> {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl<C> DrawTarget for TgaWriter<'_, C>
+where
+    C: PixelColor + EncoderColor,
+{
+    type Color = C;
+    type Error = core::convert::Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            if let Some(index) = self.index(point) {
+                self.buffer[index] = color;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let area = area.intersection(&self.bounding_box());
+        let width = area.size.width as usize;
+
+        for y in area.rows() {
+            if let Some(start) = self.index(Point::new(area.top_left.x, y)) {
+                self.buffer[start..start + width].fill(color);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<C> GetPixel for TgaWriter<'_, C>
+where
+    C: PixelColor + EncoderColor,
+{
+    type Color = C;
+
+    fn pixel(&self, point: Point) -> Option<Self::Color> {
+        self.index(point).map(|index| self.buffer[index])
+    }
+}
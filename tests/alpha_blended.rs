@@ -0,0 +1,168 @@
+use embedded_graphics::{
+    framebuffer::{buffer_size, Framebuffer},
+    image::{GetPixel, Image},
+    mock_display::MockDisplay,
+    pixelcolor::{
+        raw::{LittleEndian, RawU24},
+        Rgb555, Rgb888,
+    },
+    prelude::*,
+    primitives::Rectangle,
+};
+use tinytga::Tga;
+
+const COLOR_PATTERN: &[&str] = &[
+    "WKRGBYMCW",
+    "KKRGBYMCW",
+    "WKRGBYMCW",
+    "KKKKKKKKK",
+    "WKWCMYBGR",
+];
+
+/// Returns the alpha value used in the 32 bpp test images.
+fn alpha_32bpp(p: Point) -> u8 {
+    [0xFF, 0x80, 0x00][(p.x + p.y) as usize % 3]
+}
+
+fn mix(foreground: Rgb888, background: Rgb888, alpha: u8) -> Rgb888 {
+    let mix_channel = |f: u8, b: u8| {
+        ((u16::from(f) * u16::from(alpha) + u16::from(b) * (255 - u16::from(alpha)) + 127) / 255)
+            as u8
+    };
+
+    Rgb888::new(
+        mix_channel(foreground.r(), background.r()),
+        mix_channel(foreground.g(), background.g()),
+        mix_channel(foreground.b(), background.b()),
+    )
+}
+
+fn test_background(data: &[u8]) {
+    let tga = Tga::<Rgb888>::from_slice(data).unwrap();
+
+    let mut display = MockDisplay::new();
+    Image::new(&tga.alpha_blended(Rgb888::BLUE), Point::zero())
+        .draw(&mut display)
+        .unwrap();
+
+    let pattern = MockDisplay::<Rgb888>::from_pattern(COLOR_PATTERN);
+    let mut expected = MockDisplay::new();
+    for p in Rectangle::new(Point::zero(), tga.size()).points() {
+        let color = pattern.get_pixel(p).unwrap();
+        match alpha_32bpp(p) {
+            0x00 => {}
+            0xFF => expected.set_pixel(p, Some(color)),
+            alpha => expected.set_pixel(p, Some(mix(color, Rgb888::BLUE, alpha))),
+        }
+    }
+
+    display.assert_eq(&expected);
+}
+
+#[test]
+fn type2_32bpp_background() {
+    test_background(include_bytes!("type2_32bpp_tl.tga"));
+    test_background(include_bytes!("type2_32bpp_bl.tga"));
+}
+
+#[test]
+fn type10_32bpp_background() {
+    test_background(include_bytes!("type10_32bpp_tl.tga"));
+    test_background(include_bytes!("type10_32bpp_bl.tga"));
+}
+
+#[test]
+fn type2_32bpp_framebuffer() {
+    let tga = Tga::<Rgb888>::from_slice(include_bytes!("type2_32bpp_tl.tga")).unwrap();
+    let offset = Point::new(1, 2);
+
+    let mut fb =
+        Framebuffer::<Rgb888, RawU24, LittleEndian, 12, 8, { buffer_size::<Rgb888>(12, 8) }>::new();
+    fb.clear(Rgb888::CYAN).unwrap();
+
+    tga.draw_alpha_blended(&mut fb, offset).unwrap();
+
+    let pattern = MockDisplay::<Rgb888>::from_pattern(COLOR_PATTERN);
+    let image_area = Rectangle::new(offset, tga.size());
+    for p in fb.bounding_box().points() {
+        let expected = if image_area.contains(p) {
+            let color = pattern.get_pixel(p - offset).unwrap();
+            mix(color, Rgb888::CYAN, alpha_32bpp(p - offset))
+        } else {
+            Rgb888::CYAN
+        };
+
+        assert_eq!(fb.pixel(p), Some(expected), "pixel at {:?}", p);
+    }
+}
+
+#[test]
+fn framebuffer_outside_of_target() {
+    let tga = Tga::<Rgb888>::from_slice(include_bytes!("type2_32bpp_tl.tga")).unwrap();
+
+    let mut fb =
+        Framebuffer::<Rgb888, RawU24, LittleEndian, 4, 4, { buffer_size::<Rgb888>(4, 4) }>::new();
+
+    // Pixels outside the framebuffer must be skipped without causing an error.
+    tga.draw_alpha_blended(&mut fb, Point::new(-2, -2)).unwrap();
+}
+
+#[test]
+fn type2_16bpp_alpha() {
+    let tga = Tga::<Rgb555>::from_slice(include_bytes!("type2_16bpp_alpha_tl.tga")).unwrap();
+    assert_eq!(tga.as_raw().header().alpha_channel_depth, 1);
+
+    let mut display = MockDisplay::new();
+    Image::new(&tga.alpha_blended(Rgb555::BLACK), Point::zero())
+        .draw(&mut display)
+        .unwrap();
+
+    let pattern = MockDisplay::<Rgb555>::from_pattern(COLOR_PATTERN);
+    let mut expected = MockDisplay::new();
+    for p in Rectangle::new(Point::zero(), tga.size()).points() {
+        if (p.x + p.y) % 2 == 0 {
+            expected.set_pixel(p, pattern.get_pixel(p));
+        }
+    }
+
+    display.assert_eq(&expected);
+
+    // The alpha bit is ignored when the image is drawn without blending.
+    let mut display = MockDisplay::new();
+    Image::new(&tga, Point::zero()).draw(&mut display).unwrap();
+    display.assert_eq(&pattern);
+
+    // The raw pixel values contain the alpha bit.
+    let first = tga.as_raw().pixels().next().unwrap();
+    assert_eq!(first.color & 0x8000, 0x8000);
+}
+
+fn test_transparent_color_map_entry(data: &[u8]) {
+    // The black entry in the color map is fully transparent.
+    let tga = Tga::<Rgb888>::from_slice(data).unwrap();
+
+    let mut display = MockDisplay::new();
+    Image::new(&tga.alpha_blended(Rgb888::BLUE), Point::zero())
+        .draw(&mut display)
+        .unwrap();
+
+    display.assert_pattern(&[
+        "W RGBYMCW",
+        "  RGBYMCW",
+        "W RGBYMCW",
+        "         ",
+        "W WCMYBGR",
+    ]);
+}
+
+#[test]
+fn type1_32bpp_background() {
+    test_transparent_color_map_entry(include_bytes!("type1_32bpp_tl.tga"));
+    test_transparent_color_map_entry(include_bytes!("type1_32bpp_bl.tga"));
+}
+
+#[test]
+fn type9_32bpp_background() {
+    test_transparent_color_map_entry(include_bytes!("type9_32bpp_tl.tga"));
+    test_transparent_color_map_entry(include_bytes!("type9_32bpp_bl.tga"));
+}
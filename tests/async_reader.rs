@@ -0,0 +1,195 @@
+#![cfg(feature = "embedded-io-async")]
+
+use embassy_futures::block_on;
+use embedded_graphics::{image::Image, pixelcolor::Rgb888, prelude::*};
+use embedded_io_async::{ErrorKind, ErrorType, Read};
+use tinytga::{
+    AsyncTgaReader, ParseError, RawTga, ReadDrawError, ReadError, Tga, TgaReader, TgaWriter,
+};
+
+/// Reader which returns at most `max_read` bytes per call to `read`.
+struct SlowReader<'a> {
+    data: &'a [u8],
+    max_read: usize,
+}
+
+impl ErrorType for SlowReader<'_> {
+    type Error = ErrorKind;
+}
+
+impl Read for SlowReader<'_> {
+    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        let length = buf.len().min(self.data.len()).min(self.max_read);
+
+        buf[..length].copy_from_slice(&self.data[..length]);
+        self.data = &self.data[length..];
+
+        Ok(length)
+    }
+}
+
+/// Draw target which always returns an error.
+struct ErrorTarget;
+
+impl OriginDimensions for ErrorTarget {
+    fn size(&self) -> Size {
+        Size::new(100, 100)
+    }
+}
+
+impl DrawTarget for ErrorTarget {
+    type Color = Rgb888;
+    type Error = ();
+
+    fn draw_iter<I>(&mut self, _pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        Err(())
+    }
+}
+
+/// Checks that the async reader returns the same pixels as `Tga` and `TgaReader`.
+#[track_caller]
+fn assert_reader(data: &[u8]) {
+    let tga = Tga::<Rgb888>::from_slice(data).unwrap();
+    let size = tga.size();
+    let width = size.width as usize;
+
+    let mut expected = vec![Rgb888::BLACK; width * size.height as usize];
+    let mut writer = TgaWriter::new(&mut expected, size).unwrap();
+    Image::new(&tga, Point::zero()).draw(&mut writer).unwrap();
+
+    let mut color_map = [0; 1024];
+    let mut row = vec![Rgb888::BLACK; width];
+
+    // Draw the image.
+    let mut colors = vec![Rgb888::WHITE; expected.len()];
+    let mut writer = TgaWriter::new(&mut colors, size).unwrap();
+    let reader = SlowReader { data, max_read: 5 };
+    let mut reader = block_on(AsyncTgaReader::new(reader, &mut color_map)).unwrap();
+    assert_eq!(reader.header(), tga.as_raw().header());
+    assert_eq!(reader.size(), size);
+    block_on(reader.draw(&mut writer, &mut row)).unwrap();
+    assert_eq!(colors, expected);
+
+    // Read the image as rows of raw values.
+    let mut sync_color_map = [0; 1024];
+    let mut sync_reader = TgaReader::new(data, &mut sync_color_map).unwrap();
+    let mut reader = block_on(AsyncTgaReader::new(data, &mut color_map)).unwrap();
+    let mut row = vec![0; width];
+    let mut expected_row = vec![0; width];
+    loop {
+        let y = block_on(reader.read_row(&mut row)).unwrap();
+        assert_eq!(y, sync_reader.read_row(&mut expected_row).unwrap());
+        assert_eq!(row, expected_row);
+
+        if y.is_none() {
+            break;
+        }
+    }
+}
+
+#[test]
+fn color_mapped() {
+    assert_reader(include_bytes!("type1_24bpp_bl.tga"));
+    assert_reader(include_bytes!("type1_24bpp_map_start_tl.tga"));
+    assert_reader(include_bytes!("type9_16bpp_tl.tga"));
+}
+
+#[test]
+fn true_color() {
+    assert_reader(include_bytes!("type2_16bpp_bl.tga"));
+    assert_reader(include_bytes!("type2_32bpp_bl.tga"));
+    assert_reader(include_bytes!("type10_24bpp_tl.tga"));
+}
+
+#[test]
+fn grayscale() {
+    assert_reader(include_bytes!("type3_tl.tga"));
+    assert_reader(include_bytes!("type11_bl.tga"));
+}
+
+#[test]
+fn right_origin() {
+    assert_reader(include_bytes!("logo_type2_24bpp_br.tga"));
+    assert_reader(include_bytes!("type10_24bpp_scan_lines_tr.tga"));
+}
+
+#[test]
+fn image_id() {
+    assert_reader(include_bytes!("image_id.tga"));
+}
+
+#[test]
+fn pixels() {
+    let data = include_bytes!("type9_24bpp_tl.tga");
+    let raw = RawTga::from_slice(data).unwrap();
+
+    let mut color_map = [0; 1024];
+    let mut reader = block_on(AsyncTgaReader::new(&data[..], &mut color_map)).unwrap();
+
+    let mut pixels = Vec::new();
+    while let Some(pixel) = block_on(reader.next_pixel()) {
+        pixels.push(pixel.unwrap());
+    }
+
+    assert_eq!(pixels, raw.pixels().collect::<Vec<_>>());
+}
+
+#[test]
+fn read_rows_callback_error() {
+    let data = include_bytes!("type2_24bpp_tl.tga");
+
+    let mut reader = block_on(AsyncTgaReader::new(&data[..], &mut [])).unwrap();
+
+    let mut row = [Rgb888::BLACK; 9];
+    let mut rows = 0;
+    let result = block_on(reader.read_rows(&mut row, |y, _| {
+        rows += 1;
+        if y == 1 {
+            Err(ReadError::Parse(ParseError::Header))
+        } else {
+            Ok(())
+        }
+    }));
+
+    assert_eq!(result, Err(ReadError::Parse(ParseError::Header)));
+    assert_eq!(rows, 2);
+}
+
+#[test]
+fn draw_target_error() {
+    let data = include_bytes!("type2_24bpp_tl.tga");
+
+    let mut reader = block_on(AsyncTgaReader::new(&data[..], &mut [])).unwrap();
+
+    let mut row = [Rgb888::BLACK; 9];
+    assert_eq!(
+        block_on(reader.draw(&mut ErrorTarget, &mut row)),
+        Err(ReadDrawError::DrawTarget(()))
+    );
+}
+
+#[test]
+fn truncated_image_data() {
+    let data = include_bytes!("error_truncated_image_data.tga");
+
+    let mut reader = block_on(AsyncTgaReader::new(&data[..], &mut [])).unwrap();
+
+    let mut row = [0; 9];
+    assert_eq!(
+        block_on(reader.read_row(&mut row)),
+        Err(ReadError::UnexpectedEof)
+    );
+}
+
+#[test]
+fn color_map_buffer_too_small() {
+    let data = include_bytes!("type1_24bpp_tl.tga");
+
+    assert!(matches!(
+        block_on(AsyncTgaReader::new(&data[..], &mut [0; 8])),
+        Err(ReadError::BufferTooSmall { .. })
+    ));
+}
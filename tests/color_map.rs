@@ -0,0 +1,177 @@
+use embedded_graphics::{
+    image::{Image, ImageDrawableExt},
+    mock_display::{ColorMapping, MockDisplay},
+    pixelcolor::{Gray8, Rgb555, Rgb888},
+    prelude::*,
+    primitives::Rectangle,
+};
+use tinytga::{Bpp, DataType, ParseError, RawTga, Tga, TgaWriter};
+
+#[test]
+fn start_index() {
+    // The color map in "type1_24bpp_map_start_tl.tga" starts at index 3 and contains 8 entries.
+    let tga = RawTga::from_slice(include_bytes!("type1_24bpp_map_start_tl.tga")).unwrap();
+    let color_map = tga.color_map().unwrap();
+
+    assert_eq!(tga.header().color_map_start, 3);
+    assert_eq!(color_map.start_index(), 3);
+
+    assert_eq!(color_map.get_raw(0), None);
+    assert_eq!(color_map.get_raw(2), None);
+    assert_eq!(color_map.get_raw(3), Some(0x000000));
+    assert_eq!(color_map.get_raw(4), Some(0xFFFFFF));
+    assert_eq!(color_map.get_raw(5), Some(0xFF0000));
+    assert_eq!(color_map.get_raw(10), Some(0x00FFFF));
+    assert_eq!(color_map.get_raw(11), None);
+}
+
+#[test]
+fn zero_start_index() {
+    let tga = RawTga::from_slice(include_bytes!("type1_24bpp_tl.tga")).unwrap();
+    let color_map = tga.color_map().unwrap();
+
+    assert_eq!(color_map.start_index(), 0);
+
+    assert!(color_map.get_raw(0).is_some());
+    assert!(color_map.get_raw(7).is_some());
+    assert_eq!(color_map.get_raw(8), None);
+}
+
+#[test]
+fn entry_alpha() {
+    let tga = RawTga::from_slice(include_bytes!("type1_32bpp_tl.tga")).unwrap();
+    let color_map = tga.color_map().unwrap();
+
+    assert_eq!(color_map.entry_bpp(), Bpp::Bits32);
+
+    assert_eq!(color_map.get_raw(0), Some(0x00000000));
+    assert_eq!(color_map.get_raw(1), Some(0xFFFFFFFF));
+    assert_eq!(color_map.get_raw(2), Some(0xFFFF0000));
+}
+
+/// Creates a 6x1 pixel image with a color map that contains 300 entries and starts at index 2.
+fn large_color_map() -> Vec<u8> {
+    let mut data = Vec::new();
+    // ID length, color map type and image type.
+    data.extend_from_slice(&[0, 1, 1]);
+    // Color map start, length and entry depth.
+    data.extend_from_slice(&[2, 0, 44, 1, 24]);
+    // X and Y origin.
+    data.extend_from_slice(&[0; 4]);
+    // Width, height, pixel depth and image descriptor.
+    data.extend_from_slice(&[6, 0, 1, 0, 16, 0x20]);
+
+    for entry in 0..300u16 {
+        let [low, high] = entry.to_le_bytes();
+        data.extend_from_slice(&[0x55, high, low]);
+    }
+
+    // The first and the last index are outside the color map.
+    for index in [0u16, 2, 257, 258, 301, 302] {
+        data.extend_from_slice(&index.to_le_bytes());
+    }
+
+    data
+}
+
+#[test]
+fn large_color_map_lookup() {
+    let data = large_color_map();
+    let tga = Tga::<Rgb888>::from_slice(&data)
+        .unwrap()
+        .with_fallback_color(Rgb888::CSS_PINK);
+
+    let expected = [
+        Rgb888::CSS_PINK,
+        Rgb888::new(0, 0, 0x55),
+        Rgb888::new(255, 0, 0x55),
+        Rgb888::new(0, 1, 0x55),
+        Rgb888::new(43, 1, 0x55),
+        Rgb888::CSS_PINK,
+    ];
+
+    let mut colors = [Rgb888::BLACK; 6];
+    TgaWriter::new(&mut colors[..], tga.size())
+        .map(|mut writer| Image::new(&tga, Point::zero()).draw(&mut writer))
+        .unwrap()
+        .unwrap();
+    assert_eq!(colors, expected);
+
+    let mut colors = [Rgb888::BLACK; 6];
+    let area = Rectangle::new(Point::new(1, 0), Size::new(4, 1));
+    TgaWriter::new(&mut colors[1..5], area.size)
+        .map(|mut writer| Image::new(&tga.sub_image(&area), Point::zero()).draw(&mut writer))
+        .unwrap()
+        .unwrap();
+    assert_eq!(colors[1..5], expected[1..5]);
+
+    let mut colors = [Rgb888::BLACK; 6];
+    tga.decode_into(&mut colors).unwrap();
+    assert_eq!(colors, expected);
+
+    let colors: Vec<_> = tga.pixels().map(|Pixel(_, color)| color).collect();
+    assert_eq!(colors, expected);
+}
+
+/// Checks that `draw`, `draw_sub_image` and `fill_runs` draw the same pixels.
+#[track_caller]
+fn assert_entry_points_match<C>(data: &[u8])
+where
+    C: PixelColor + From<Gray8> + From<Rgb555> + From<Rgb888> + ColorMapping,
+{
+    let tga = Tga::<C>::from_slice(data).unwrap();
+
+    let mut expected = MockDisplay::new();
+    Image::new(&tga, Point::zero()).draw(&mut expected).unwrap();
+
+    let mut display = MockDisplay::new();
+    Image::new(&tga.sub_image(&tga.bounding_box()), Point::zero())
+        .draw(&mut display)
+        .unwrap();
+    display.assert_eq(&expected);
+
+    let mut display = MockDisplay::new();
+    Image::new(&tga.fill_runs(), Point::zero())
+        .draw(&mut display)
+        .unwrap();
+    display.assert_eq(&expected);
+}
+
+#[test]
+fn color_mapped_entry_points_match() {
+    for data in [
+        &include_bytes!("type1_24bpp_tl.tga")[..],
+        include_bytes!("type9_16bpp_bl.tga"),
+        include_bytes!("type9_24bpp_idx16_tl.tga"),
+        include_bytes!("type9_24bpp_map_start_bl.tga"),
+        include_bytes!("type9_24bpp_scan_lines_tr.tga"),
+    ] {
+        assert_entry_points_match::<Rgb888>(data);
+        assert_entry_points_match::<Rgb555>(data);
+        assert_entry_points_match::<Gray8>(data);
+    }
+}
+
+#[test]
+fn gray8_color_map_is_rejected() {
+    let mut data = Vec::new();
+    // ID length, color map type and image type.
+    data.extend_from_slice(&[0, 1, 1]);
+    // Color map start, length and entry depth.
+    data.extend_from_slice(&[0, 0, 2, 0, 8]);
+    // X and Y origin.
+    data.extend_from_slice(&[0; 4]);
+    // Width, height, pixel depth and image descriptor.
+    data.extend_from_slice(&[1, 0, 1, 0, 8, 0x20]);
+    // Color map and image data.
+    data.extend_from_slice(&[0x10, 0x20, 1]);
+
+    // Images with 8 bit grayscale color map entries can't be drawn by any of the draw methods.
+    assert_eq!(
+        Tga::<Gray8>::from_slice(&data),
+        Err(ParseError::UnsupportedTgaType(
+            DataType::ColorMapped,
+            Bpp::Bits8
+        ))
+    );
+}
@@ -0,0 +1,28 @@
+//! Helpers which are shared by multiple integration tests.
+
+use embedded_graphics::{pixelcolor::Rgb888, prelude::*};
+use tinytga::{Compression, ImageOrigin, TgaEncoder};
+
+/// Length of the TGA header in bytes.
+const HEADER_LENGTH: usize = 18;
+
+/// Creates a RLE compressed 24 BPP image which contains the given image data.
+///
+/// The header is written by the encoder, but the image data is used as is. This makes it possible
+/// to create images the encoder doesn't produce, like images with run packets that cross row
+/// boundaries or truncated image data.
+pub fn rle_image(size: Size, image_origin: ImageOrigin, image_data: &[u8]) -> Vec<u8> {
+    let colors = vec![Rgb888::BLACK; (size.width * size.height) as usize];
+
+    let mut data = Vec::new();
+    TgaEncoder::new(size)
+        .with_compression(Compression::Rle)
+        .with_image_origin(image_origin)
+        .encode(&colors, &mut data)
+        .unwrap();
+
+    data.truncate(HEADER_LENGTH);
+    data.extend_from_slice(image_data);
+
+    data
+}
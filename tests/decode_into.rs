@@ -0,0 +1,116 @@
+use embedded_graphics::{image::Image, pixelcolor::Rgb888, prelude::*};
+use tinytga::{ImageOrigin, ParseError, Tga, TgaWriter};
+
+/// Checks that `decode_into` returns the same colors as drawing the image.
+#[track_caller]
+fn assert_decode_into(data: &[u8]) {
+    let tga = Tga::<Rgb888>::from_slice(data).unwrap();
+    let raw = tga.as_raw();
+    let size = tga.size();
+    let width = size.width as usize;
+    let pixel_count = width * size.height as usize;
+
+    let mut expected = vec![Rgb888::BLACK; pixel_count];
+    let mut writer = TgaWriter::new(&mut expected, size).unwrap();
+    Image::new(&tga, Point::zero()).draw(&mut writer).unwrap();
+
+    let mut colors = vec![Rgb888::WHITE; pixel_count];
+    tga.decode_into(&mut colors).unwrap();
+    assert_eq!(colors, expected);
+
+    let mut values = vec![0; pixel_count];
+    raw.decode_into(&mut values).unwrap();
+    for (y, row) in values.chunks(width).enumerate() {
+        let mut expected: Vec<_> = raw.row(y as u32).unwrap().collect();
+        if matches!(
+            raw.image_origin(),
+            ImageOrigin::TopRight | ImageOrigin::BottomRight
+        ) {
+            expected.reverse();
+        }
+
+        assert_eq!(row, expected, "row {y}");
+    }
+
+    let bytes_per_pixel = usize::from(raw.image_data_bpp().bytes());
+    let mut bytes = vec![0; pixel_count * bytes_per_pixel];
+    raw.decode_into_bytes(&mut bytes).unwrap();
+    for (value, bytes) in values.iter().zip(bytes.chunks(bytes_per_pixel)) {
+        assert_eq!(bytes, &value.to_le_bytes()[0..bytes_per_pixel]);
+    }
+}
+
+#[test]
+fn all_origins() {
+    assert_decode_into(include_bytes!("logo_type2_24bpp_tl.tga"));
+    assert_decode_into(include_bytes!("logo_type2_24bpp_bl.tga"));
+    assert_decode_into(include_bytes!("logo_type2_24bpp_tr.tga"));
+    assert_decode_into(include_bytes!("logo_type2_24bpp_br.tga"));
+}
+
+#[test]
+fn rle() {
+    assert_decode_into(include_bytes!("type10_16bpp_bl.tga"));
+    assert_decode_into(include_bytes!("type10_32bpp_tl.tga"));
+    assert_decode_into(include_bytes!("type11_bl.tga"));
+    assert_decode_into(include_bytes!("type10_24bpp_scan_lines_tr.tga"));
+    assert_decode_into(include_bytes!("type10_24bpp_scan_lines_br.tga"));
+}
+
+#[test]
+fn color_mapped() {
+    assert_decode_into(include_bytes!("type1_16bpp_tl.tga"));
+    assert_decode_into(include_bytes!("type1_24bpp_idx16_bl.tga"));
+    assert_decode_into(include_bytes!("type1_24bpp_map_start_tl.tga"));
+    assert_decode_into(include_bytes!("type9_24bpp_bl.tga"));
+    assert_decode_into(include_bytes!("type9_24bpp_idx16_tl.tga"));
+}
+
+#[test]
+fn grayscale() {
+    assert_decode_into(include_bytes!("type3_tl.tga"));
+}
+
+#[test]
+fn invalid_color_index() {
+    // The second pixel in "error_color_index.tga" uses the index 5, but the color map only
+    // contains 2 entries.
+    let tga = Tga::<Rgb888>::from_slice(include_bytes!("error_color_index.tga"))
+        .unwrap()
+        .with_fallback_color(Rgb888::CSS_PINK);
+
+    let mut colors = vec![Rgb888::BLACK; tga.size().width as usize * tga.size().height as usize];
+    tga.decode_into(&mut colors).unwrap();
+
+    assert_eq!(colors[1], Rgb888::CSS_PINK);
+}
+
+#[test]
+fn mismatched_length() {
+    let tga = Tga::<Rgb888>::from_slice(include_bytes!("type2_24bpp_tl.tga")).unwrap();
+    let raw = tga.as_raw();
+
+    assert_eq!(
+        tga.decode_into(&mut [Rgb888::BLACK; 10]),
+        Err(ParseError::MismatchedLength {
+            expected: 45,
+            actual: 10
+        })
+    );
+
+    assert_eq!(
+        raw.decode_into(&mut [0; 46]),
+        Err(ParseError::MismatchedLength {
+            expected: 45,
+            actual: 46
+        })
+    );
+
+    assert_eq!(
+        raw.decode_into_bytes(&mut [0; 45]),
+        Err(ParseError::MismatchedLength {
+            expected: 135,
+            actual: 45
+        })
+    );
+}
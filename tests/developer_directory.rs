@@ -0,0 +1,52 @@
+use tinytga::{DeveloperTag, RawTga};
+
+#[test]
+fn developer_tags() {
+    let tga = RawTga::from_slice(include_bytes!("developer_directory.tga")).unwrap();
+
+    let tags: Vec<_> = tga.developer_tags().collect();
+
+    // The entry with data outside the file is skipped.
+    assert_eq!(
+        tags,
+        [
+            DeveloperTag {
+                tag: 0x1000,
+                data: &[1, 0, 2, 0],
+            },
+            DeveloperTag {
+                tag: 0x1001,
+                data: &[100, 0, 50, 0, 100, 0],
+            },
+            DeveloperTag {
+                tag: 0x1002,
+                data: &[],
+            },
+            DeveloperTag {
+                tag: 0x1004,
+                data: &[100, 0, 50, 0, 100, 0],
+            },
+        ]
+    );
+}
+
+#[test]
+fn truncated_directory() {
+    let tga = RawTga::from_slice(include_bytes!("developer_directory_truncated.tga")).unwrap();
+
+    let tags: Vec<_> = tga.developer_tags().collect();
+    assert_eq!(
+        tags,
+        [DeveloperTag {
+            tag: 0x2000,
+            data: &[0, 0, 3, 0],
+        }]
+    );
+}
+
+#[test]
+fn no_developer_directory() {
+    let tga = RawTga::from_slice(include_bytes!("cbw8.tga")).unwrap();
+
+    assert_eq!(tga.developer_tags().next(), None);
+}
@@ -0,0 +1,167 @@
+use embedded_graphics::{
+    image::{Image, ImageDrawableExt},
+    mock_display::{ColorMapping, MockDisplay},
+    pixelcolor::{Gray8, Rgb555, Rgb888},
+    prelude::*,
+    primitives::Rectangle,
+};
+use tinytga::{ColorType, DynamicTga, ParseError, RawTga, Tga};
+
+#[track_caller]
+fn assert_color_type(data: &[u8], expected: ColorType) {
+    assert_eq!(RawTga::from_slice(data).unwrap().color_type(), Ok(expected));
+    assert_eq!(
+        Tga::<Rgb888>::from_slice(data).unwrap().color_type(),
+        expected
+    );
+    assert_eq!(DynamicTga::from_slice(data).unwrap().color_type(), expected);
+}
+
+#[test]
+fn color_types() {
+    assert_color_type(include_bytes!("type3_tl.tga"), ColorType::Gray8);
+    assert_color_type(include_bytes!("type11_bl.tga"), ColorType::Gray8);
+    assert_color_type(include_bytes!("type2_16bpp_tl.tga"), ColorType::Rgb555);
+    assert_color_type(include_bytes!("type9_16bpp_tl.tga"), ColorType::Rgb555);
+    assert_color_type(include_bytes!("type2_24bpp_tl.tga"), ColorType::Rgb888);
+    assert_color_type(
+        include_bytes!("type1_24bpp_idx16_tl.tga"),
+        ColorType::Rgb888,
+    );
+    assert_color_type(include_bytes!("type2_32bpp_tl.tga"), ColorType::Argb8888);
+    assert_color_type(include_bytes!("type1_32bpp_tl.tga"), ColorType::Argb8888);
+}
+
+#[test]
+fn bpp() {
+    assert_eq!(ColorType::Gray8.bpp().bits(), 8);
+    assert_eq!(ColorType::Rgb555.bpp().bits(), 16);
+    assert_eq!(ColorType::Rgb888.bpp().bits(), 24);
+    assert_eq!(ColorType::Argb8888.bpp().bits(), 32);
+}
+
+/// Checks that a dynamic image draws the same pixels as an image with a fixed color type.
+#[track_caller]
+fn assert_draw<C>(dynamic: &Tga<C>, data: &[u8])
+where
+    C: PixelColor + From<Gray8> + From<Rgb555> + From<Rgb888> + ColorMapping,
+{
+    let mut expected = MockDisplay::new();
+    Image::new(&Tga::<C>::from_slice(data).unwrap(), Point::zero())
+        .draw(&mut expected)
+        .unwrap();
+
+    let mut display = MockDisplay::new();
+    Image::new(dynamic, Point::zero())
+        .draw(&mut display)
+        .unwrap();
+
+    display.assert_eq(&expected);
+}
+
+#[test]
+fn draw_variants() {
+    let data = include_bytes!("type3_bl.tga");
+    match DynamicTga::from_slice(data).unwrap() {
+        DynamicTga::Gray8(tga) => assert_draw(&tga, data),
+        other => panic!("unexpected variant: {other:?}"),
+    }
+
+    let data = include_bytes!("type10_16bpp_tl.tga");
+    match DynamicTga::from_slice(data).unwrap() {
+        DynamicTga::Rgb555(tga) => assert_draw(&tga, data),
+        other => panic!("unexpected variant: {other:?}"),
+    }
+
+    let data = include_bytes!("type9_24bpp_bl.tga");
+    match DynamicTga::from_slice(data).unwrap() {
+        DynamicTga::Rgb888(tga) => assert_draw(&tga, data),
+        other => panic!("unexpected variant: {other:?}"),
+    }
+
+    let data = include_bytes!("type2_32bpp_bl.tga");
+    match DynamicTga::from_slice(data).unwrap() {
+        DynamicTga::Argb8888(tga) => assert_draw(&tga, data),
+        other => panic!("unexpected variant: {other:?}"),
+    }
+}
+
+/// Checks that drawing a dynamic image draws the same pixels as an `Rgb888` image.
+#[track_caller]
+fn assert_draw_dynamic(data: &[u8]) {
+    let dynamic = DynamicTga::from_slice(data).unwrap();
+    let tga = Tga::<Rgb888>::from_slice(data).unwrap();
+
+    let mut expected = MockDisplay::new();
+    Image::new(&tga, Point::zero()).draw(&mut expected).unwrap();
+
+    let mut display = MockDisplay::new();
+    Image::new(&dynamic, Point::zero())
+        .draw(&mut display)
+        .unwrap();
+
+    display.assert_eq(&expected);
+
+    let area = Rectangle::new(Point::new(1, 2), Size::new(3, 2));
+
+    let mut expected = MockDisplay::new();
+    Image::new(&tga.sub_image(&area), Point::zero())
+        .draw(&mut expected)
+        .unwrap();
+
+    let mut display = MockDisplay::new();
+    Image::new(&dynamic.sub_image(&area), Point::zero())
+        .draw(&mut display)
+        .unwrap();
+
+    display.assert_eq(&expected);
+}
+
+#[test]
+fn draw_dynamic() {
+    assert_draw_dynamic(include_bytes!("type3_bl.tga"));
+    assert_draw_dynamic(include_bytes!("type10_16bpp_tl.tga"));
+    assert_draw_dynamic(include_bytes!("type9_24bpp_bl.tga"));
+    assert_draw_dynamic(include_bytes!("type2_32bpp_bl.tga"));
+}
+
+#[test]
+fn draw_dynamic_color_converted() {
+    let data = include_bytes!("type3_bl.tga");
+
+    let mut expected = MockDisplay::<Gray8>::new();
+    Image::new(&Tga::from_slice(data).unwrap(), Point::zero())
+        .draw(&mut expected)
+        .unwrap();
+
+    let mut display = MockDisplay::<Gray8>::new();
+    Image::new(&DynamicTga::from_slice(data).unwrap(), Point::zero())
+        .draw(&mut display.color_converted())
+        .unwrap();
+
+    display.assert_eq(&expected);
+}
+
+#[test]
+fn size_and_raw() {
+    let data = include_bytes!("logo_type2_16bpp_tl.tga");
+    let tga = DynamicTga::from_slice(data).unwrap();
+
+    assert_eq!(tga.size(), Size::new(240, 320));
+    assert_eq!(tga.as_raw(), &RawTga::from_slice(data).unwrap());
+}
+
+#[test]
+fn unsupported_color_type() {
+    // "error_index_bpp.tga" is a color mapped image with 24 bit color indices.
+    let data = include_bytes!("error_index_bpp.tga");
+
+    assert_eq!(
+        RawTga::from_slice(data).unwrap().color_type(),
+        Err(ParseError::UnsupportedBpp(24))
+    );
+    assert_eq!(
+        DynamicTga::from_slice(data),
+        Err(ParseError::UnsupportedBpp(24))
+    );
+}
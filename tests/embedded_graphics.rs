@@ -120,10 +120,14 @@ macro_rules! test_tga {
 // Type 1: color mapped, uncompressed
 test_tga!(type1_16bpp, Rgb555);
 test_tga!(type1_24bpp, Rgb888);
+test_tga!(type1_24bpp_map_start, Rgb888);
+test_tga!(type1_32bpp, Rgb888);
+test_tga!(type1_24bpp_idx16, Rgb888);
 
 // Type 2: true color, uncompressed
 test_tga!(type2_16bpp, Rgb555);
 test_tga!(type2_24bpp, Rgb888);
+test_tga!(type2_32bpp, Rgb888);
 
 // Type 3: grayscale, uncompressed
 test_tga!(type3, Gray8);
@@ -131,10 +135,14 @@ test_tga!(type3, Gray8);
 // Type 9: color mapped, RLE compressed
 test_tga!(type9_16bpp, Rgb555);
 test_tga!(type9_24bpp, Rgb888);
+test_tga!(type9_24bpp_map_start, Rgb888);
+test_tga!(type9_32bpp, Rgb888);
+test_tga!(type9_24bpp_idx16, Rgb888);
 
 // Type 10: true color, RLE compressed
 test_tga!(type10_16bpp, Rgb555);
 test_tga!(type10_24bpp, Rgb888);
+test_tga!(type10_32bpp, Rgb888);
 
 // Type 11: grayscale, RLE compressed
 test_tga!(type11, Gray8);
@@ -0,0 +1,714 @@
+use core::time::Duration;
+use embedded_graphics::{
+    image::Image,
+    mock_display::MockDisplay,
+    pixelcolor::{
+        raw::{RawU24, RawU32},
+        Gray8, Rgb555, Rgb888,
+    },
+    prelude::*,
+};
+use tinytga::{
+    Bpp, Compression, DataType, DateTime, DeveloperTag, EncodeError, EncoderColor, ImageOrigin,
+    RawTga, Tga, TgaEncoder, TgaExtension,
+};
+
+const COLOR_PATTERN: &[&str] = &[
+    "WKRGBYMCW",
+    "KKRGBYMCW",
+    "WKRGBYMCW",
+    "KKKKKKKKK",
+    "WKWCMYBGR",
+];
+
+const ORIGINS: [ImageOrigin; 4] = [
+    ImageOrigin::TopLeft,
+    ImageOrigin::TopRight,
+    ImageOrigin::BottomLeft,
+    ImageOrigin::BottomRight,
+];
+
+/// Returns the colors of an image in top left origin, row-major order.
+fn colors<C>(data: &[u8]) -> Vec<C>
+where
+    C: PixelColor + From<Gray8> + From<Rgb555> + From<Rgb888>,
+{
+    let tga = Tga::<C>::from_slice(data).unwrap();
+    let width = tga.size().width as usize;
+
+    let mut pixels: Vec<_> = tga.pixels().collect();
+    pixels.sort_by_key(|Pixel(p, _)| p.y as usize * width + p.x as usize);
+
+    pixels.into_iter().map(|Pixel(_, c)| c).collect()
+}
+
+fn encode<C: EncoderColor>(encoder: TgaEncoder<C>, colors: &[C]) -> Vec<u8> {
+    let mut output = Vec::new();
+    encoder.encode(colors, &mut output).unwrap();
+
+    output
+}
+
+#[test]
+fn type2_24bpp() {
+    let colors = colors::<Rgb888>(include_bytes!("type2_24bpp_tl.tga"));
+    let encoder = TgaEncoder::new(Size::new(9, 5));
+
+    assert_eq!(
+        encode(encoder, &colors),
+        include_bytes!("type2_24bpp_tl.tga")
+    );
+    assert_eq!(
+        encode(encoder.with_image_origin(ImageOrigin::BottomLeft), &colors),
+        include_bytes!("type2_24bpp_bl.tga")
+    );
+}
+
+#[test]
+fn type2_16bpp() {
+    let colors = colors::<Rgb555>(include_bytes!("type2_16bpp_tl.tga"));
+    let encoder = TgaEncoder::new(Size::new(9, 5));
+
+    assert_eq!(
+        encode(encoder, &colors),
+        include_bytes!("type2_16bpp_tl.tga")
+    );
+    assert_eq!(
+        encode(encoder.with_image_origin(ImageOrigin::BottomLeft), &colors),
+        include_bytes!("type2_16bpp_bl.tga")
+    );
+}
+
+#[test]
+fn type2_32bpp() {
+    let tga = RawTga::from_slice(include_bytes!("type2_32bpp_tl.tga")).unwrap();
+    let colors: Vec<_> = tga.pixels().map(|p| RawU32::new(p.color)).collect();
+    let encoder = TgaEncoder::new(Size::new(9, 5));
+
+    assert_eq!(
+        encode(encoder, &colors),
+        include_bytes!("type2_32bpp_tl.tga")
+    );
+    assert_eq!(
+        encode(encoder.with_image_origin(ImageOrigin::BottomLeft), &colors),
+        include_bytes!("type2_32bpp_bl.tga")
+    );
+}
+
+#[test]
+fn type3() {
+    let colors = colors::<Gray8>(include_bytes!("type3_tl.tga"));
+    let encoder = TgaEncoder::new(Size::new(9, 5));
+
+    assert_eq!(encode(encoder, &colors), include_bytes!("type3_tl.tga"));
+    assert_eq!(
+        encode(encoder.with_image_origin(ImageOrigin::BottomLeft), &colors),
+        include_bytes!("type3_bl.tga")
+    );
+}
+
+#[test]
+fn image_origins() {
+    let colors = colors::<Rgb888>(include_bytes!("type2_24bpp_tl.tga"));
+
+    for image_origin in ORIGINS {
+        let encoder = TgaEncoder::new(Size::new(9, 5)).with_image_origin(image_origin);
+        let data = encode(encoder, &colors);
+
+        let tga = Tga::<Rgb888>::from_slice(&data).unwrap();
+        assert_eq!(tga.as_raw().image_origin(), image_origin);
+
+        let mut display = MockDisplay::new();
+        Image::new(&tga, Point::zero()).draw(&mut display).unwrap();
+        display.assert_pattern(COLOR_PATTERN);
+    }
+}
+
+#[test]
+fn image_id() {
+    let encoder = TgaEncoder::new(Size::new(1, 1)).with_image_id(b"e-g");
+    let data = encode(encoder, &[Rgb888::RED]);
+
+    let tga = RawTga::from_slice(&data).unwrap();
+    assert_eq!(tga.image_id(), Some(&b"e-g"[..]));
+    assert_eq!(tga.pixels().next().unwrap().color, 0xFF0000);
+}
+
+#[test]
+fn appends_to_output() {
+    let mut output = vec![1, 2, 3];
+    TgaEncoder::new(Size::new(1, 1))
+        .encode(&[Gray8::WHITE], &mut output)
+        .unwrap();
+
+    assert_eq!(&output[0..3], &[1, 2, 3]);
+    assert!(RawTga::from_slice(&output[3..]).is_ok());
+}
+
+#[test]
+fn mismatched_length() {
+    let mut output = Vec::new();
+    let result = TgaEncoder::new(Size::new(2, 2)).encode(&[Rgb888::RED; 3], &mut output);
+
+    assert_eq!(
+        result,
+        Err(EncodeError::MismatchedLength {
+            expected: 4,
+            actual: 3
+        })
+    );
+    assert!(output.is_empty());
+}
+
+#[test]
+fn image_too_large() {
+    let mut output = Vec::new();
+    let result = TgaEncoder::<Rgb888>::new(Size::new(65536, 0)).encode(&[], &mut output);
+
+    assert_eq!(result, Err(EncodeError::ImageTooLarge));
+    assert!(output.is_empty());
+}
+
+#[test]
+fn image_id_too_long() {
+    let image_id = [b'a'; 256];
+
+    let mut output = Vec::new();
+    let result = TgaEncoder::new(Size::new(1, 1))
+        .with_image_id(&image_id)
+        .encode(&[Rgb888::RED], &mut output);
+
+    assert_eq!(result, Err(EncodeError::ImageIdTooLong));
+    assert!(output.is_empty());
+}
+
+#[test]
+fn type1_24bpp() {
+    let data = include_bytes!("type1_24bpp_tl.tga");
+    let raw = RawTga::from_slice(data).unwrap();
+    let color_map: Vec<_> = (0..)
+        .map_while(|index| raw.color_map().unwrap().get_raw(index))
+        .map(|raw| Rgb888::from(RawU24::new(raw)))
+        .collect();
+
+    let colors = colors::<Rgb888>(data);
+    let encoder = TgaEncoder::new(Size::new(9, 5)).with_color_map(&color_map);
+
+    assert_eq!(encode(encoder, &colors), data);
+}
+
+#[test]
+fn type9() {
+    let colors = colors::<Rgb555>(include_bytes!("type2_16bpp_tl.tga"));
+    let mut color_map = colors.clone();
+    color_map.sort_by_key(|color| color.into_storage());
+    color_map.dedup();
+
+    for image_origin in ORIGINS {
+        let encoder = TgaEncoder::new(Size::new(9, 5))
+            .with_image_origin(image_origin)
+            .with_compression(Compression::Rle)
+            .with_color_map(&color_map);
+        let data = encode(encoder, &colors);
+
+        let tga = Tga::<Rgb888>::from_slice(&data).unwrap();
+        let raw = tga.as_raw();
+        assert_eq!(raw.data_type(), DataType::ColorMapped);
+        assert_eq!(raw.compression(), Compression::Rle);
+        assert_eq!(raw.image_data_bpp(), Bpp::Bits8);
+        assert_eq!(raw.color_map().unwrap().entry_bpp(), Bpp::Bits16);
+
+        let mut display = MockDisplay::new();
+        Image::new(&tga, Point::zero()).draw(&mut display).unwrap();
+        display.assert_pattern(COLOR_PATTERN);
+    }
+}
+
+#[test]
+fn color_map_16bit_indices() {
+    let color_map: Vec<_> = (0..300).map(RawU32::new).collect();
+    let colors = [color_map[299], color_map[0], color_map[256], color_map[299]];
+
+    let encoder = TgaEncoder::new(Size::new(2, 2)).with_color_map(&color_map);
+    let data = encode(encoder, &colors);
+    assert_eq!(data.len(), encoder.max_encoded_size());
+
+    let raw = RawTga::from_slice(&data).unwrap();
+    assert_eq!(raw.image_data_bpp(), Bpp::Bits16);
+    assert_eq!(raw.color_map().unwrap().entry_bpp(), Bpp::Bits32);
+    assert!(raw.pixels().map(|p| p.color).eq([299, 0, 256, 299]));
+}
+
+#[test]
+fn color_map_postage_stamp() {
+    let colors = colors::<Rgb888>(include_bytes!("type2_24bpp_tl.tga"));
+    let color_map = [
+        Rgb888::BLACK,
+        Rgb888::WHITE,
+        Rgb888::RED,
+        Rgb888::GREEN,
+        Rgb888::BLUE,
+        Rgb888::YELLOW,
+        Rgb888::MAGENTA,
+        Rgb888::CYAN,
+    ];
+    let stamp = [Rgb888::RED, Rgb888::GREEN, Rgb888::BLUE, Rgb888::WHITE];
+
+    let encoder = TgaEncoder::new(Size::new(9, 5))
+        .with_color_map(&color_map)
+        .with_postage_stamp(&stamp, Size::new(2, 2));
+    let data = encode(encoder, &colors);
+    assert_eq!(data.len(), encoder.max_encoded_size());
+
+    let tga = Tga::<Rgb888>::from_slice(&data).unwrap();
+
+    let mut display = MockDisplay::new();
+    Image::new(&tga, Point::zero()).draw(&mut display).unwrap();
+    display.assert_pattern(COLOR_PATTERN);
+
+    let mut display = MockDisplay::new();
+    Image::new(&tga.postage_stamp().unwrap(), Point::zero())
+        .draw(&mut display)
+        .unwrap();
+    display.assert_pattern(&["RG", "BW"]);
+}
+
+#[test]
+fn invalid_color_map() {
+    let mut output = Vec::new();
+
+    let color_map = [Rgb888::RED, Rgb888::GREEN];
+    let encoder = TgaEncoder::new(Size::new(2, 1)).with_color_map(&color_map);
+    assert_eq!(
+        encoder.encode(&[Rgb888::RED, Rgb888::BLUE], &mut output),
+        Err(EncodeError::ColorNotInColorMap)
+    );
+
+    let stamp = [Rgb888::BLUE];
+    let encoder = encoder.with_postage_stamp(&stamp, Size::new(1, 1));
+    assert_eq!(
+        encoder.encode(&[Rgb888::RED, Rgb888::GREEN], &mut output),
+        Err(EncodeError::ColorNotInColorMap)
+    );
+
+    let color_map = [Gray8::BLACK, Gray8::WHITE];
+    let encoder = TgaEncoder::new(Size::new(1, 1)).with_color_map(&color_map);
+    assert_eq!(
+        encoder.encode(&[Gray8::BLACK], &mut output),
+        Err(EncodeError::UnsupportedColorMap)
+    );
+
+    let color_map = vec![Rgb555::BLACK; 65536];
+    let encoder = TgaEncoder::new(Size::new(1, 1)).with_color_map(&color_map);
+    assert_eq!(
+        encoder.encode(&[Rgb555::BLACK], &mut output),
+        Err(EncodeError::ColorMapTooLarge)
+    );
+
+    assert!(output.is_empty());
+}
+
+/// Checks that the RLE compressed image contains the same pixels as the uncompressed image.
+fn test_rle<C: EncoderColor>(size: Size, colors: &[C]) {
+    for image_origin in ORIGINS {
+        let encoder = TgaEncoder::new(size).with_image_origin(image_origin);
+
+        let uncompressed = encode(encoder, colors);
+        let rle = encode(encoder.with_compression(Compression::Rle), colors);
+
+        let uncompressed = RawTga::from_slice(&uncompressed).unwrap();
+        let rle = RawTga::from_slice(&rle).unwrap();
+        assert_eq!(rle.compression(), Compression::Rle);
+        assert_eq!(rle.image_origin(), image_origin);
+        assert!(rle.pixels().eq(uncompressed.pixels()));
+    }
+}
+
+#[test]
+fn type10_24bpp() {
+    test_rle(
+        Size::new(9, 5),
+        &colors::<Rgb888>(include_bytes!("type2_24bpp_tl.tga")),
+    );
+}
+
+#[test]
+fn type10_16bpp() {
+    test_rle(
+        Size::new(9, 5),
+        &colors::<Rgb555>(include_bytes!("type2_16bpp_tl.tga")),
+    );
+}
+
+#[test]
+fn type10_32bpp() {
+    let tga = RawTga::from_slice(include_bytes!("type2_32bpp_tl.tga")).unwrap();
+    let colors: Vec<_> = tga.pixels().map(|p| RawU32::new(p.color)).collect();
+
+    test_rle(Size::new(9, 5), &colors);
+}
+
+#[test]
+fn type11() {
+    test_rle(
+        Size::new(9, 5),
+        &colors::<Gray8>(include_bytes!("type3_tl.tga")),
+    );
+}
+
+#[test]
+fn type10_logo() {
+    test_rle(
+        Size::new(240, 320),
+        &colors::<Rgb888>(include_bytes!("logo_type2_24bpp_tl.tga")),
+    );
+}
+
+/// Returns the length of each packet in the RLE compressed image data.
+fn packet_lengths(data: &[u8], bytes_per_pixel: usize) -> Vec<usize> {
+    let tga = RawTga::from_slice(data).unwrap();
+    let mut image_data = tga.image_data();
+
+    let mut lengths = Vec::new();
+    while let Some((packet_header, rest)) = image_data.split_first() {
+        let length = usize::from(packet_header & 0x7F) + 1;
+        let data_length = if packet_header & 0x80 != 0 {
+            bytes_per_pixel
+        } else {
+            length * bytes_per_pixel
+        };
+
+        lengths.push(length);
+        image_data = &rest[data_length..];
+    }
+
+    lengths
+}
+
+#[test]
+fn rle_long_runs() {
+    let encoder = TgaEncoder::new(Size::new(300, 2)).with_compression(Compression::Rle);
+    let data = encode(encoder, &[Gray8::WHITE; 600]);
+
+    assert_eq!(packet_lengths(&data, 1), [128, 128, 44, 128, 128, 44]);
+}
+
+#[test]
+fn rle_long_raw_packets() {
+    let colors: Vec<_> = (0..600).map(|i| Gray8::new(i as u8)).collect();
+
+    let encoder = TgaEncoder::new(Size::new(300, 2)).with_compression(Compression::Rle);
+    let data = encode(encoder, &colors);
+
+    assert_eq!(packet_lengths(&data, 1), [128, 128, 44, 128, 128, 44]);
+}
+
+#[test]
+fn rle_packets_dont_cross_rows() {
+    // Each row ends with the same color as the first pixel of the next row.
+    let colors: Vec<_> = (0..20)
+        .map(|i| {
+            if i % 5 == 0 || i % 5 == 4 {
+                Gray8::WHITE
+            } else {
+                Gray8::new(i)
+            }
+        })
+        .collect();
+
+    let encoder = TgaEncoder::new(Size::new(5, 4)).with_compression(Compression::Rle);
+    let data = encode(encoder, &colors);
+
+    let mut column = 0;
+    for length in packet_lengths(&data, 1) {
+        column += length;
+        assert!(column <= 5);
+        column %= 5;
+    }
+    assert_eq!(column, 0);
+}
+
+#[test]
+fn encode_into() {
+    let colors = colors::<Rgb888>(include_bytes!("type2_24bpp_tl.tga"));
+    let encoder = TgaEncoder::new(Size::new(9, 5));
+
+    assert_eq!(encoder.max_encoded_size(), 153);
+
+    let mut output = [0; 153];
+    assert_eq!(encoder.encode_into(&colors, &mut output), Ok(153));
+    assert_eq!(&output, include_bytes!("type2_24bpp_tl.tga"));
+
+    let mut output = [0; 200];
+    assert_eq!(encoder.encode_into(&colors, &mut output), Ok(153));
+    assert_eq!(&output[..153], include_bytes!("type2_24bpp_tl.tga"));
+}
+
+#[test]
+fn encode_into_rle() {
+    let colors = colors::<Rgb888>(include_bytes!("type2_24bpp_tl.tga"));
+    let encoder = TgaEncoder::new(Size::new(9, 5)).with_compression(Compression::Rle);
+
+    let expected = encode(encoder, &colors);
+    assert!(encoder.max_encoded_size() >= expected.len());
+
+    let mut output = vec![0; encoder.max_encoded_size()];
+    assert_eq!(
+        encoder.encode_into(&colors, &mut output),
+        Ok(expected.len())
+    );
+    assert_eq!(&output[..expected.len()], expected);
+}
+
+#[test]
+fn buffer_too_small() {
+    let colors = colors::<Rgb888>(include_bytes!("type2_24bpp_tl.tga"));
+    let encoder = TgaEncoder::new(Size::new(9, 5));
+
+    let mut output = [0; 152];
+    assert_eq!(
+        encoder.encode_into(&colors, &mut output),
+        Err(EncodeError::BufferTooSmall { required: 153 })
+    );
+
+    // The required size for RLE compressed images is determined by encoding the entire image.
+    let encoder = encoder.with_compression(Compression::Rle);
+    let expected = encode(encoder, &colors);
+
+    let mut output = [0; 20];
+    assert_eq!(
+        encoder.encode_into(&colors, &mut output),
+        Err(EncodeError::BufferTooSmall {
+            required: expected.len()
+        })
+    );
+}
+
+#[test]
+fn encode_into_mismatched_length() {
+    let mut output = [0; 10];
+    let result = TgaEncoder::new(Size::new(2, 2)).encode_into(&[Rgb888::RED; 3], &mut output);
+
+    assert_eq!(
+        result,
+        Err(EncodeError::MismatchedLength {
+            expected: 4,
+            actual: 3
+        })
+    );
+}
+
+#[test]
+fn max_encoded_size() {
+    let encoder = TgaEncoder::<Gray8>::new(Size::new(10, 20)).with_image_id(b"abc");
+    assert_eq!(encoder.max_encoded_size(), 18 + 3 + 200);
+
+    let encoder = encoder.with_compression(Compression::Rle);
+    assert_eq!(encoder.max_encoded_size(), 18 + 3 + 400);
+}
+
+#[test]
+fn max_encoded_size_saturates() {
+    // The size is calculated before the postage stamp size is checked.
+    let encoder = TgaEncoder::<Rgb888>::new(Size::new(1, 1))
+        .with_postage_stamp(&[], Size::new(u32::MAX, u32::MAX));
+    assert_eq!(encoder.max_encoded_size(), usize::MAX);
+
+    let encoder = TgaEncoder::<Rgb888>::new(Size::new(u32::MAX, u32::MAX));
+    assert_eq!(encoder.max_encoded_size(), usize::MAX);
+}
+
+fn extension() -> TgaExtension<'static> {
+    TgaExtension {
+        author_name: b"Author",
+        author_comments: [b"Line 1", b"", b"Line 3", b""],
+        date_time: Some(DateTime {
+            year: 2024,
+            month: 5,
+            day: 17,
+            hour: 13,
+            minute: 14,
+            second: 15,
+        }),
+        job_name: b"Job",
+        job_time: Duration::from_secs(3 * 3600 + 2 * 60 + 1),
+        software_id: b"tinytga",
+        software_version: Some(60),
+        software_version_letter: Some(b'a'),
+        key_color: 0xFF00FF00,
+        pixel_aspect_ratio: Some((1, 2)),
+        gamma: Some((22, 10)),
+        color_correction_offset: None,
+        postage_stamp_offset: None,
+        scan_line_offset: None,
+        attributes_type: 2,
+    }
+}
+
+#[test]
+fn extension_area() {
+    let colors = colors::<Rgb888>(include_bytes!("type2_24bpp_tl.tga"));
+    let encoder = TgaEncoder::new(Size::new(9, 5)).with_extension(TgaExtension {
+        // Offsets in the extension area are ignored by the encoder.
+        scan_line_offset: Some(1234),
+        ..extension()
+    });
+    let data = encode(encoder, &colors);
+    assert_eq!(data.len(), encoder.max_encoded_size());
+
+    let tga = RawTga::from_slice(&data).unwrap();
+    assert_eq!(tga.extension(), Some(extension()));
+    assert_eq!(
+        tga.image_data(),
+        &include_bytes!("type2_24bpp_tl.tga")[18..]
+    );
+    assert_eq!(tga.developer_directory(), None);
+}
+
+#[test]
+fn postage_stamp() {
+    let colors = colors::<Rgb888>(include_bytes!("type2_24bpp_tl.tga"));
+    let stamp = [Rgb888::RED, Rgb888::GREEN, Rgb888::BLUE, Rgb888::WHITE];
+
+    for image_origin in ORIGINS {
+        let encoder = TgaEncoder::new(Size::new(9, 5))
+            .with_image_origin(image_origin)
+            .with_compression(Compression::Rle)
+            .with_postage_stamp(&stamp, Size::new(2, 2));
+        let data = encode(encoder, &colors);
+
+        let tga = Tga::<Rgb888>::from_slice(&data).unwrap();
+        let extension = tga.as_raw().extension().unwrap();
+        assert!(extension.postage_stamp_offset.is_some());
+        assert_eq!(
+            extension,
+            TgaExtension {
+                postage_stamp_offset: extension.postage_stamp_offset,
+                ..TgaExtension::default()
+            }
+        );
+
+        let mut display = MockDisplay::new();
+        Image::new(&tga, Point::zero()).draw(&mut display).unwrap();
+        display.assert_pattern(COLOR_PATTERN);
+
+        let mut display = MockDisplay::new();
+        Image::new(&tga.postage_stamp().unwrap(), Point::zero())
+            .draw(&mut display)
+            .unwrap();
+        display.assert_pattern(&["RG", "BW"]);
+    }
+}
+
+#[test]
+fn developer_tags() {
+    let tags = [
+        DeveloperTag {
+            tag: 1,
+            data: b"hotspot",
+        },
+        DeveloperTag { tag: 2, data: &[] },
+        DeveloperTag {
+            tag: 3,
+            data: &[1, 2, 3],
+        },
+    ];
+
+    let encoder = TgaEncoder::new(Size::new(2, 1)).with_developer_tags(&tags);
+    let data = encode(encoder, &[Gray8::BLACK, Gray8::WHITE]);
+    assert_eq!(data.len(), encoder.max_encoded_size());
+
+    let tga = RawTga::from_slice(&data).unwrap();
+    assert!(tga.developer_tags().eq(tags));
+    assert_eq!(tga.extension_area(), None);
+    assert_eq!(tga.image_data(), &[0x00, 0xFF]);
+}
+
+#[test]
+fn all_sections() {
+    let tags = [DeveloperTag {
+        tag: 0x1234,
+        data: b"build 42",
+    }];
+    let stamp = [Gray8::new(0x80)];
+
+    let encoder = TgaEncoder::new(Size::new(2, 1))
+        .with_image_id(b"id")
+        .with_extension(extension())
+        .with_postage_stamp(&stamp, Size::new(1, 1))
+        .with_developer_tags(&tags);
+    let data = encode(encoder, &[Gray8::BLACK, Gray8::WHITE]);
+    assert_eq!(data.len(), encoder.max_encoded_size());
+    assert!(data.ends_with(b"TRUEVISION-XFILE.\0"));
+
+    let tga = RawTga::from_slice(&data).unwrap();
+    assert_eq!(tga.image_id(), Some(&b"id"[..]));
+    assert_eq!(tga.image_data(), &[0x00, 0xFF]);
+    assert!(tga.developer_tags().eq(tags));
+
+    let extension = tga.extension().unwrap();
+    assert_eq!(
+        extension,
+        TgaExtension {
+            postage_stamp_offset: extension.postage_stamp_offset,
+            ..self::extension()
+        }
+    );
+
+    let stamp = tga.postage_stamp().unwrap();
+    assert_eq!(stamp.size(), Size::new(1, 1));
+    assert_eq!(stamp.pixels().next().unwrap().color, 0x80);
+
+    // The metadata of the main image isn't returned for the postage stamp.
+    assert_eq!(stamp.extension_area(), None);
+    assert_eq!(stamp.extension(), None);
+    assert_eq!(stamp.scan_line_table(), None);
+    assert_eq!(stamp.developer_tags().count(), 0);
+    assert_eq!(stamp.postage_stamp(), None);
+}
+
+#[test]
+fn extension_field_too_long() {
+    let encoder = TgaEncoder::new(Size::new(1, 1)).with_extension(TgaExtension {
+        author_name: &[b'a'; 41],
+        ..TgaExtension::default()
+    });
+
+    let mut output = Vec::new();
+    assert_eq!(
+        encoder.encode(&[Gray8::BLACK], &mut output),
+        Err(EncodeError::ExtensionFieldTooLong)
+    );
+    assert!(output.is_empty());
+
+    let encoder = TgaEncoder::new(Size::new(1, 1)).with_extension(TgaExtension {
+        author_comments: [b"", b"", b"", &[b'a'; 81]],
+        ..TgaExtension::default()
+    });
+    assert_eq!(
+        encoder.encode(&[Gray8::BLACK], &mut output),
+        Err(EncodeError::ExtensionFieldTooLong)
+    );
+}
+
+#[test]
+fn invalid_postage_stamp() {
+    let stamp = [Gray8::BLACK; 256];
+    let mut output = Vec::new();
+
+    let encoder = TgaEncoder::new(Size::new(1, 1)).with_postage_stamp(&stamp, Size::new(256, 1));
+    assert_eq!(
+        encoder.encode(&[Gray8::BLACK], &mut output),
+        Err(EncodeError::ImageTooLarge)
+    );
+
+    let encoder = TgaEncoder::new(Size::new(1, 1)).with_postage_stamp(&stamp, Size::new(2, 2));
+    assert_eq!(
+        encoder.encode(&[Gray8::BLACK], &mut output),
+        Err(EncodeError::MismatchedLength {
+            expected: 4,
+            actual: 256
+        })
+    );
+    assert!(output.is_empty());
+}
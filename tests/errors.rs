@@ -1,6 +1,14 @@
-use embedded_graphics::{prelude::*, primitives::Rectangle};
+use embedded_graphics::{
+    image::{Image, ImageDrawableExt},
+    mock_display::MockDisplay,
+    pixelcolor::Rgb888,
+    prelude::*,
+    primitives::Rectangle,
+};
 use std::iter::repeat;
-use tinytga::{ParseError, RawPixel, RawTga};
+use tinytga::{DrawError, ImageOrigin, ParseError, RawPixel, RawTga, Tga};
+
+mod common;
 
 #[test]
 fn color_map() {
@@ -47,6 +55,97 @@ fn image_data_truncated() {
     assert_eq!(pixels, expected);
 }
 
+/// Creates a 4x2 pixel RLE compressed image which only contains a single run of 4 red pixels.
+fn truncated_run(image_origin: ImageOrigin) -> Vec<u8> {
+    common::rle_image(Size::new(4, 2), image_origin, &[0x80 | 3, 0x00, 0x00, 0xFF])
+}
+
+#[track_caller]
+fn assert_truncated_run(data: &[u8], pattern: &[&str]) {
+    let tga = Tga::<Rgb888>::from_slice(data).unwrap();
+
+    let mut display = MockDisplay::<Rgb888>::new();
+    Image::new(&tga, Point::zero()).draw(&mut display).unwrap();
+    display.assert_pattern(pattern);
+
+    let mut display = MockDisplay::<Rgb888>::new();
+    Image::new(&tga.fill_runs(), Point::zero())
+        .draw(&mut display)
+        .unwrap();
+    display.assert_pattern(pattern);
+
+    let mut display = MockDisplay::<Rgb888>::new();
+    Image::new(&tga.sub_image(&tga.bounding_box()), Point::zero())
+        .draw(&mut display)
+        .unwrap();
+    display.assert_pattern(pattern);
+
+    let mut display = MockDisplay::<Rgb888>::new();
+    tga.pixels()
+        .filter(|p| p.1 != Rgb888::BLACK)
+        .draw(&mut display)
+        .unwrap();
+    display.assert_pattern(pattern);
+}
+
+#[test]
+fn image_data_truncated_right_origin() {
+    assert_truncated_run(&truncated_run(ImageOrigin::TopRight), &["RRRR"]);
+    assert_truncated_run(&truncated_run(ImageOrigin::BottomRight), &["    ", "RRRR"]);
+}
+
+#[test]
+fn invalid_color_index() {
+    // The second pixel in "error_color_index.tga" uses the index 5, but the color map only
+    // contains 2 entries.
+    let tga = Tga::<Rgb888>::from_slice(include_bytes!("../tests/error_color_index.tga")).unwrap();
+
+    assert_eq!(
+        tga.check_color_indices(),
+        Err(ParseError::InvalidColorIndex(5))
+    );
+
+    let mut display = MockDisplay::<Rgb888>::new();
+    assert_eq!(
+        tga.try_draw(&mut display),
+        Err(DrawError::Parse(ParseError::InvalidColorIndex(5)))
+    );
+    display.assert_pattern(&[]);
+
+    // Invalid indices are replaced by the fallback color when the image is drawn.
+    let mut display = MockDisplay::<Rgb888>::new();
+    Image::new(&tga, Point::zero()).draw(&mut display).unwrap();
+    display.assert_pattern(&["WKK"]);
+
+    let tga = tga.with_fallback_color(Rgb888::RED);
+
+    let mut display = MockDisplay::<Rgb888>::new();
+    Image::new(&tga, Point::zero()).draw(&mut display).unwrap();
+    display.assert_pattern(&["WRK"]);
+
+    let colors: Vec<_> = tga.pixels().map(|Pixel(_, c)| c).collect();
+    assert_eq!(colors, [Rgb888::WHITE, Rgb888::RED, Rgb888::BLACK]);
+}
+
+#[test]
+fn unsupported_color_index_bpp() {
+    // "error_index_bpp.tga" is a color mapped image with 24 bit color indices.
+    assert_eq!(
+        Tga::<Rgb888>::from_slice(include_bytes!("../tests/error_index_bpp.tga")),
+        Err(ParseError::UnsupportedBpp(24))
+    );
+}
+
+#[test]
+fn valid_color_indices() {
+    let tga = Tga::<Rgb888>::from_slice(include_bytes!("../tests/type9_24bpp_tl.tga")).unwrap();
+    assert_eq!(tga.check_color_indices(), Ok(()));
+
+    // Images without a color map are always valid.
+    let tga = Tga::<Rgb888>::from_slice(include_bytes!("../tests/type2_24bpp_tl.tga")).unwrap();
+    assert_eq!(tga.check_color_indices(), Ok(()));
+}
+
 // #[test]
 // fn mismatched_bpp() {
 //     // type2_tl_24bpp.tga is a 24 BPP image
@@ -0,0 +1,88 @@
+use core::time::Duration;
+use tinytga::{DateTime, RawTga, TgaExtension};
+
+#[test]
+fn cbw8() {
+    let data = include_bytes!("./cbw8.tga");
+
+    let img = RawTga::from_slice(data).unwrap();
+
+    assert_eq!(
+        img.extension(),
+        Some(TgaExtension {
+            author_name: b"Ricky True",
+            author_comments: [
+                b"Sample 8 bit run length compressed black and white image",
+                b"",
+                b"",
+                b"",
+            ],
+            date_time: Some(DateTime {
+                year: 1990,
+                month: 3,
+                day: 24,
+                hour: 10,
+                minute: 0,
+                second: 0,
+            }),
+            job_name: b"TGA Utilities",
+            job_time: Duration::ZERO,
+            software_id: b"TGAEdit",
+            software_version: Some(200),
+            software_version_letter: None,
+            key_color: 0,
+            pixel_aspect_ratio: None,
+            gamma: None,
+            color_correction_offset: None,
+            postage_stamp_offset: Some(4140),
+            scan_line_offset: None,
+            attributes_type: 0,
+        })
+    );
+}
+
+#[test]
+fn all_fields() {
+    let data = include_bytes!("./extension_area.tga");
+
+    let img = RawTga::from_slice(data).unwrap();
+
+    // The extension area must not be included in the image data.
+    assert_eq!(img.image_data().len(), 2 * 2 * 3);
+
+    assert_eq!(
+        img.extension(),
+        Some(TgaExtension {
+            author_name: b"embedded-graphics",
+            author_comments: [b"line 1", b"line 2", b"", b"line 4"],
+            date_time: Some(DateTime {
+                year: 2023,
+                month: 5,
+                day: 17,
+                hour: 12,
+                minute: 34,
+                second: 56,
+            }),
+            job_name: b"tinytga",
+            job_time: Duration::from_secs(3600 + 2 * 60 + 3),
+            software_id: b"tinytga",
+            software_version: Some(417),
+            software_version_letter: Some(b'b'),
+            key_color: 0xFF112233,
+            pixel_aspect_ratio: Some((1, 2)),
+            gamma: Some((22, 10)),
+            color_correction_offset: None,
+            postage_stamp_offset: None,
+            scan_line_offset: None,
+            attributes_type: 3,
+        })
+    );
+}
+
+#[test]
+fn no_extension_area() {
+    // chessboard_4px_raw.tga contains a footer without an extension area
+    let img = RawTga::from_slice(include_bytes!("./chessboard_4px_raw.tga")).unwrap();
+
+    assert_eq!(img.extension(), None);
+}
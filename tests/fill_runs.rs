@@ -0,0 +1,141 @@
+use embedded_graphics::{
+    image::{Image, ImageDrawableExt},
+    mock_display::MockDisplay,
+    pixelcolor::Rgb888,
+    prelude::*,
+    primitives::Rectangle,
+};
+use tinytga::{ImageOrigin, Tga};
+
+mod common;
+
+/// Draw target that counts the number of `fill_solid` calls.
+struct FillSolidTarget {
+    size: Size,
+    pixels: Vec<Rgb888>,
+    fill_solid_calls: usize,
+}
+
+impl FillSolidTarget {
+    fn new(size: Size) -> Self {
+        Self {
+            size,
+            pixels: vec![Rgb888::BLACK; size.width as usize * size.height as usize],
+            fill_solid_calls: 0,
+        }
+    }
+}
+
+impl DrawTarget for FillSolidTarget {
+    type Color = Rgb888;
+    type Error = core::convert::Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(p, c) in pixels {
+            assert!(self.bounding_box().contains(p), "{p:?}");
+            self.pixels[p.y as usize * self.size.width as usize + p.x as usize] = c;
+        }
+
+        Ok(())
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        assert_eq!(area.size.height, 1);
+        self.fill_solid_calls += 1;
+
+        self.draw_iter(area.points().map(|p| Pixel(p, color)))
+    }
+}
+
+impl OriginDimensions for FillSolidTarget {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+/// Checks that `fill_runs` draws the same pixels as `decode_into` and returns the number of
+/// `fill_solid` calls.
+#[track_caller]
+fn assert_fill_runs(data: &[u8]) -> usize {
+    let tga = Tga::<Rgb888>::from_slice(data).unwrap();
+
+    let mut expected = vec![Rgb888::BLACK; tga.size().width as usize * tga.size().height as usize];
+    tga.decode_into(&mut expected).unwrap();
+
+    let mut target = FillSolidTarget::new(tga.size());
+    Image::new(&tga.fill_runs(), Point::zero())
+        .draw(&mut target)
+        .unwrap();
+
+    assert_eq!(target.pixels, expected);
+
+    target.fill_solid_calls
+}
+
+#[test]
+fn all_origins() {
+    assert!(assert_fill_runs(include_bytes!("type10_24bpp_scan_lines_tl.tga")) > 0);
+    assert!(assert_fill_runs(include_bytes!("type10_24bpp_scan_lines_bl.tga")) > 0);
+    assert!(assert_fill_runs(include_bytes!("type10_24bpp_scan_lines_tr.tga")) > 0);
+    assert!(assert_fill_runs(include_bytes!("type10_24bpp_scan_lines_br.tga")) > 0);
+}
+
+#[test]
+fn logo() {
+    assert!(assert_fill_runs(include_bytes!("logo_type10_24bpp_tl.tga")) > 0);
+    assert!(assert_fill_runs(include_bytes!("logo_type10_16bpp_bl.tga")) > 0);
+    assert!(assert_fill_runs(include_bytes!("logo_type11_tl.tga")) > 0);
+}
+
+#[test]
+fn color_mapped() {
+    assert_fill_runs(include_bytes!("type9_16bpp_tl.tga"));
+    assert_fill_runs(include_bytes!("type9_24bpp_bl.tga"));
+    assert_fill_runs(include_bytes!("type9_24bpp_idx16_tl.tga"));
+    assert_fill_runs(include_bytes!("type9_32bpp_bl.tga"));
+}
+
+/// Creates a 10x3 pixel RLE compressed image with a single run packet.
+fn single_run(image_origin: ImageOrigin) -> Vec<u8> {
+    // Run packet with 30 pixels.
+    common::rle_image(
+        Size::new(10, 3),
+        image_origin,
+        &[0x80 | 29, 0x00, 0x80, 0xFF],
+    )
+}
+
+#[test]
+fn runs_are_split_at_row_boundaries() {
+    assert_eq!(assert_fill_runs(&single_run(ImageOrigin::TopLeft)), 3);
+    assert_eq!(assert_fill_runs(&single_run(ImageOrigin::BottomRight)), 3);
+}
+
+#[test]
+fn uncompressed() {
+    assert_eq!(
+        assert_fill_runs(include_bytes!("logo_type2_24bpp_br.tga")),
+        0
+    );
+}
+
+#[test]
+fn sub_image() {
+    let tga = Tga::<Rgb888>::from_slice(include_bytes!("type10_24bpp_bl.tga")).unwrap();
+    let area = Rectangle::new(Point::new(2, 1), Size::new(5, 3));
+
+    let mut expected = MockDisplay::new();
+    Image::new(&tga.sub_image(&area), Point::zero())
+        .draw(&mut expected)
+        .unwrap();
+
+    let mut display = MockDisplay::new();
+    Image::new(&tga.fill_runs().sub_image(&area), Point::zero())
+        .draw(&mut display)
+        .unwrap();
+
+    display.assert_eq(&expected);
+}
@@ -0,0 +1,94 @@
+use embedded_graphics::pixelcolor::{Gray8, Rgb888};
+use tinytga::{include_tga, ColorType, ParseError, RawTga, Tga};
+
+#[test]
+fn include_tga() {
+    let tga: Tga<Rgb888> = include_tga!("chessboard_4px_rle.tga");
+    assert_eq!(
+        tga,
+        Tga::from_slice(include_bytes!("chessboard_4px_rle.tga")).unwrap()
+    );
+
+    let tga: Tga<Gray8> = include_tga!("type3_tl.tga");
+    assert_eq!(tga.color_type(), ColorType::Gray8);
+}
+
+#[test]
+fn include_tga_in_const_context() {
+    const CHESSBOARD: Tga<Rgb888> = include_tga!("chessboard_4px_rle.tga");
+    static GRAY: Tga<Gray8> = include_tga!("type3_tl.tga");
+
+    assert_eq!(
+        CHESSBOARD,
+        Tga::from_slice(include_bytes!("chessboard_4px_rle.tga")).unwrap()
+    );
+    assert_eq!(GRAY.color_type(), ColorType::Gray8);
+}
+
+#[test]
+fn from_slice_in_const_context() {
+    const RAW: Result<RawTga, ParseError> =
+        RawTga::from_slice(include_bytes!("type1_24bpp_tl.tga"));
+
+    let data = include_bytes!("type1_24bpp_tl.tga");
+    assert_eq!(RAW, RawTga::from_slice(data));
+}
+
+#[test]
+fn check_in_const_context() {
+    const COLOR_TYPE: Result<ColorType, ParseError> =
+        RawTga::check(include_bytes!("type1_32bpp_bl.tga"));
+
+    assert_eq!(COLOR_TYPE, Ok(ColorType::Argb8888));
+}
+
+/// Checks that `RawTga::check` returns the same result as `Tga::from_slice`.
+#[track_caller]
+fn assert_check(data: &[u8]) {
+    assert_eq!(
+        RawTga::check(data),
+        Tga::<Rgb888>::from_slice(data).map(|tga| tga.color_type())
+    );
+}
+
+#[test]
+fn check_matches_from_slice() {
+    let mut count = 0;
+
+    for entry in std::fs::read_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/tests")).unwrap() {
+        let path = entry.unwrap().path();
+        if path.extension().is_some_and(|extension| extension == "tga") {
+            assert_check(&std::fs::read(path).unwrap());
+            count += 1;
+        }
+    }
+
+    assert!(count > 0);
+}
+
+#[test]
+fn check_errors() {
+    assert_eq!(RawTga::check(&[]), Err(ParseError::Header));
+    assert_eq!(
+        RawTga::check(include_bytes!("error_index_bpp.tga")),
+        Err(ParseError::UnsupportedBpp(24))
+    );
+
+    let data = include_bytes!("type1_24bpp_tl.tga");
+
+    // Truncated header.
+    assert_check(&data[0..17]);
+    // Truncated color map.
+    assert_check(&data[0..20]);
+    // Invalid color map type.
+    let mut invalid = data.to_vec();
+    invalid[1] = 2;
+    assert_check(&invalid);
+    // Color map without an entry depth.
+    invalid[1] = 1;
+    invalid[7] = 0;
+    assert_check(&invalid);
+    // Unsupported image type.
+    invalid[2] = 4;
+    assert_check(&invalid);
+}
@@ -0,0 +1,51 @@
+use embedded_graphics::{image::Image, mock_display::MockDisplay, pixelcolor::Gray8, prelude::*};
+use tinytga::{Compression, RawTga, Tga};
+
+#[test]
+fn cbw8() {
+    let tga = RawTga::from_slice(include_bytes!("./cbw8.tga")).unwrap();
+
+    let stamp = tga.postage_stamp().unwrap();
+    assert_eq!(stamp.size(), Size::new(64, 64));
+    assert_eq!(stamp.compression(), Compression::Uncompressed);
+    assert_eq!(stamp.image_data().len(), 64 * 64);
+
+    // The postage stamp in "cbw8.tga" is the image scaled down by a factor of 2.
+    let image: Vec<_> = tga.pixels().collect();
+    for pixel in stamp.pixels() {
+        let p = pixel.position * 2;
+        assert_eq!(
+            pixel.color,
+            image[(p.y * 128 + p.x) as usize].color,
+            "pixel at {:?}",
+            pixel.position
+        );
+    }
+}
+
+#[test]
+fn cbw8_draw() {
+    let tga = Tga::<Gray8>::from_slice(include_bytes!("./cbw8.tga")).unwrap();
+    let stamp = tga.postage_stamp().unwrap();
+
+    assert_eq!(stamp.size(), Size::new(64, 64));
+
+    let mut display = MockDisplay::new();
+    Image::new(&stamp, Point::zero())
+        .draw(&mut display)
+        .unwrap();
+
+    let mut expected = MockDisplay::new();
+    stamp.pixels().draw(&mut expected).unwrap();
+
+    display.assert_eq(&expected);
+}
+
+#[test]
+fn no_postage_stamp() {
+    let tga = RawTga::from_slice(include_bytes!("./extension_area.tga")).unwrap();
+    assert_eq!(tga.postage_stamp(), None);
+
+    let tga = RawTga::from_slice(include_bytes!("./type1_24bpp_tl.tga")).unwrap();
+    assert_eq!(tga.postage_stamp(), None);
+}
@@ -0,0 +1,308 @@
+#![cfg(feature = "embedded-io")]
+
+use embedded_graphics::{image::Image, pixelcolor::Rgb888, prelude::*};
+use embedded_io::{ErrorKind, ErrorType, Read, Seek, SeekFrom};
+use tinytga::{ImageOrigin, ParseError, RawPixel, RawTga, ReadError, Tga, TgaReader, TgaWriter};
+
+/// In memory reader which returns at most `max_read` bytes per call to `read`.
+struct Cursor<'a> {
+    data: &'a [u8],
+    position: usize,
+    max_read: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(data: &'a [u8], max_read: usize) -> Self {
+        Self {
+            data,
+            position: 0,
+            max_read,
+        }
+    }
+}
+
+impl ErrorType for Cursor<'_> {
+    type Error = ErrorKind;
+}
+
+impl Read for Cursor<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        let remaining = &self.data[self.position.min(self.data.len())..];
+        let length = buf.len().min(remaining.len()).min(self.max_read);
+
+        buf[..length].copy_from_slice(&remaining[..length]);
+        self.position += length;
+
+        Ok(length)
+    }
+}
+
+impl Seek for Cursor<'_> {
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64, Self::Error> {
+        let position = match pos {
+            SeekFrom::Start(offset) => offset as i64,
+            SeekFrom::End(offset) => self.data.len() as i64 + offset,
+            SeekFrom::Current(offset) => self.position as i64 + offset,
+        };
+
+        self.position = usize::try_from(position).map_err(|_| ErrorKind::InvalidInput)?;
+
+        Ok(self.position as u64)
+    }
+}
+
+/// Reader which always returns an error.
+struct ErrorReader;
+
+impl ErrorType for ErrorReader {
+    type Error = ErrorKind;
+}
+
+impl Read for ErrorReader {
+    fn read(&mut self, _buf: &mut [u8]) -> Result<usize, Self::Error> {
+        Err(ErrorKind::Other)
+    }
+}
+
+/// Checks that the reader returns the same pixels as `Tga` and `RawTga`.
+#[track_caller]
+fn assert_reader(data: &[u8]) {
+    let tga = Tga::<Rgb888>::from_slice(data).unwrap();
+    let raw = tga.as_raw();
+    let size = tga.size();
+    let width = size.width as usize;
+
+    let mut expected = vec![Rgb888::BLACK; width * size.height as usize];
+    let mut writer = TgaWriter::new(&mut expected, size).unwrap();
+    Image::new(&tga, Point::zero()).draw(&mut writer).unwrap();
+
+    let mut color_map = [0; 1024];
+
+    // Read the image as rows of colors.
+    let mut reader = TgaReader::new(Cursor::new(data, 7), &mut color_map).unwrap();
+    assert_eq!(reader.header(), raw.header());
+    assert_eq!(reader.size(), size);
+    assert_eq!(reader.color_map(), raw.color_map());
+
+    let mut colors = vec![Rgb888::WHITE; expected.len()];
+    let mut row = vec![Rgb888::BLACK; width];
+    let mut rows = 0;
+    while let Some(y) = reader.read_row_colors(&mut row).unwrap() {
+        colors[y as usize * width..][..width].copy_from_slice(&row);
+        rows += 1;
+    }
+    assert_eq!(rows, size.height);
+    assert_eq!(colors, expected);
+
+    // Read the image as rows of raw values.
+    let mut reader = TgaReader::new(Cursor::new(data, 64), &mut color_map).unwrap();
+    let mut row = vec![0; width];
+    while let Some(y) = reader.read_row(&mut row).unwrap() {
+        let expected: Vec<_> = raw.row(y).unwrap().collect();
+
+        if matches!(
+            raw.image_origin(),
+            ImageOrigin::TopRight | ImageOrigin::BottomRight
+        ) {
+            row.reverse();
+        }
+        assert_eq!(row, expected, "row {y}");
+    }
+
+    // Read the image as pixels.
+    let reader = TgaReader::new(data, &mut color_map).unwrap();
+    let pixels: Vec<_> = reader.map(Result::unwrap).collect();
+    let expected: Vec<_> = raw
+        .pixels()
+        .map(|pixel| {
+            // The reader mirrors the X coordinates of images with the origin on the right side.
+            let mut position = pixel.position;
+            if matches!(
+                raw.image_origin(),
+                ImageOrigin::TopRight | ImageOrigin::BottomRight
+            ) {
+                position.x = width as i32 - 1 - position.x;
+            }
+
+            RawPixel::new(position, pixel.color)
+        })
+        .collect();
+    assert_eq!(pixels.len(), expected.len());
+    for (pixel, expected) in pixels.iter().zip(expected.iter()) {
+        assert_eq!(pixel, expected);
+    }
+}
+
+#[test]
+fn color_mapped() {
+    assert_reader(include_bytes!("type1_16bpp_tl.tga"));
+    assert_reader(include_bytes!("type1_16bpp_bl.tga"));
+    assert_reader(include_bytes!("type1_24bpp_idx16_tl.tga"));
+    assert_reader(include_bytes!("type1_24bpp_map_start_bl.tga"));
+    assert_reader(include_bytes!("type1_32bpp_tl.tga"));
+    assert_reader(include_bytes!("type9_24bpp_bl.tga"));
+    assert_reader(include_bytes!("type9_24bpp_idx16_bl.tga"));
+}
+
+#[test]
+fn true_color() {
+    assert_reader(include_bytes!("type2_16bpp_tl.tga"));
+    assert_reader(include_bytes!("type2_24bpp_bl.tga"));
+    assert_reader(include_bytes!("type2_32bpp_tl.tga"));
+    assert_reader(include_bytes!("type10_16bpp_tl.tga"));
+    assert_reader(include_bytes!("type10_32bpp_bl.tga"));
+}
+
+#[test]
+fn grayscale() {
+    assert_reader(include_bytes!("type3_bl.tga"));
+    assert_reader(include_bytes!("type11_tl.tga"));
+}
+
+#[test]
+fn right_origin() {
+    assert_reader(include_bytes!("logo_type2_24bpp_tr.tga"));
+    assert_reader(include_bytes!("logo_type2_24bpp_br.tga"));
+    assert_reader(include_bytes!("type10_24bpp_scan_lines_tr.tga"));
+    assert_reader(include_bytes!("type10_24bpp_scan_lines_br.tga"));
+}
+
+#[test]
+fn image_id_and_extension() {
+    assert_reader(include_bytes!("image_id.tga"));
+    assert_reader(include_bytes!("extension_area.tga"));
+}
+
+#[test]
+fn read_extension() {
+    let data = include_bytes!("extension_area.tga");
+    let raw = RawTga::from_slice(data).unwrap();
+
+    let mut reader = TgaReader::new(Cursor::new(data, 16), &mut []).unwrap();
+    let first = reader.next().unwrap().unwrap();
+
+    let mut buffer = [0; 495];
+    assert_eq!(reader.read_extension(&mut buffer), Ok(raw.extension()));
+
+    // Reading the extension area doesn't change the position in the image data.
+    let pixels: Vec<_> = core::iter::once(first)
+        .chain(reader.map(Result::unwrap))
+        .collect();
+    assert_eq!(pixels, raw.pixels().collect::<Vec<_>>());
+}
+
+#[test]
+fn read_extension_without_footer() {
+    let data = include_bytes!("type2_24bpp_tl.tga");
+
+    let mut reader = TgaReader::new(Cursor::new(data, 16), &mut []).unwrap();
+
+    let mut buffer = [0; 495];
+    assert_eq!(reader.read_extension(&mut buffer), Ok(None));
+}
+
+#[test]
+fn extension_buffer_too_small() {
+    let data = include_bytes!("extension_area.tga");
+
+    let mut reader = TgaReader::new(Cursor::new(data, 16), &mut []).unwrap();
+
+    let mut buffer = [0; 100];
+    assert_eq!(
+        reader.read_extension(&mut buffer),
+        Err(ReadError::BufferTooSmall { required: 495 })
+    );
+}
+
+#[test]
+fn color_map_buffer_too_small() {
+    let data = include_bytes!("type1_24bpp_tl.tga");
+    let raw = RawTga::from_slice(data).unwrap();
+    let required =
+        raw.color_map().unwrap().entry_bpp().bytes() as usize * raw.header().color_map_len as usize;
+
+    assert_eq!(
+        TgaReader::new(&data[..], &mut [0; 8]).err(),
+        Some(ReadError::BufferTooSmall { required })
+    );
+}
+
+#[test]
+fn row_buffer_too_small() {
+    let data = include_bytes!("type2_24bpp_tl.tga");
+
+    let mut reader = TgaReader::new(&data[..], &mut []).unwrap();
+    assert_eq!(
+        reader.read_row(&mut [0; 2]),
+        Err(ReadError::BufferTooSmall { required: 9 })
+    );
+}
+
+#[test]
+fn truncated_header() {
+    let data = include_bytes!("type2_24bpp_tl.tga");
+
+    assert_eq!(
+        TgaReader::new(&data[0..10], &mut []).err(),
+        Some(ReadError::UnexpectedEof)
+    );
+}
+
+#[test]
+fn truncated_color_map() {
+    // The color map in "error_color_map.tga" has 65535 entries and is larger than the file.
+    let data = include_bytes!("error_color_map.tga");
+
+    let mut color_map = vec![0; 65535 * 3];
+    assert_eq!(
+        TgaReader::new(&data[..], &mut color_map).err(),
+        Some(ReadError::UnexpectedEof)
+    );
+}
+
+#[test]
+fn truncated_image_data() {
+    let data = include_bytes!("error_truncated_image_data.tga");
+
+    let reader = TgaReader::new(&data[..], &mut []).unwrap();
+    let pixels: Vec<_> = reader.map(|pixel| pixel.map(|pixel| pixel.color)).collect();
+
+    assert_eq!(&pixels[0..8], &[1, 2, 3, 4, 5, 6, 7, 8].map(Ok));
+    assert_eq!(pixels[8], Err(ReadError::UnexpectedEof));
+    // The reader stops after the first error.
+    assert_eq!(pixels.len(), 9);
+}
+
+#[test]
+fn invalid_color_index() {
+    let data = include_bytes!("error_color_index.tga");
+    let width = RawTga::from_slice(data).unwrap().size().width as usize;
+
+    let mut color_map = [0; 64];
+    let mut reader = TgaReader::new(&data[..], &mut color_map).unwrap();
+
+    let mut row = vec![Rgb888::BLACK; width];
+    assert_eq!(
+        reader.read_row_colors(&mut row),
+        Err(ReadError::Parse(ParseError::InvalidColorIndex(5)))
+    );
+}
+
+#[test]
+fn unsupported_color_index_bpp() {
+    let data = include_bytes!("error_index_bpp.tga");
+
+    let mut color_map = [0; 1024];
+    assert_eq!(
+        TgaReader::new(&data[..], &mut color_map).err(),
+        Some(ReadError::Parse(ParseError::UnsupportedBpp(24)))
+    );
+}
+
+#[test]
+fn read_error() {
+    assert_eq!(
+        TgaReader::new(ErrorReader, &mut []).err(),
+        Some(ReadError::Read(ErrorKind::Other))
+    );
+}
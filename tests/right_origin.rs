@@ -0,0 +1,101 @@
+use embedded_graphics::{image::Image, pixelcolor::Rgb888, prelude::*, primitives::Rectangle};
+use tinytga::Tga;
+
+/// Draw target that only accepts `fill_contiguous` calls.
+struct ContiguousTarget {
+    size: Size,
+    pixels: Vec<Rgb888>,
+}
+
+impl ContiguousTarget {
+    fn new(size: Size) -> Self {
+        Self {
+            size,
+            pixels: vec![Rgb888::BLACK; size.width as usize * size.height as usize],
+        }
+    }
+}
+
+impl DrawTarget for ContiguousTarget {
+    type Color = Rgb888;
+    type Error = core::convert::Infallible;
+
+    fn draw_iter<I>(&mut self, _pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        panic!("draw_iter was called");
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        assert_eq!(area.intersection(&self.bounding_box()), *area);
+
+        let mut count = 0;
+        for (p, c) in area.points().zip(colors) {
+            self.pixels[p.y as usize * self.size.width as usize + p.x as usize] = c;
+            count += 1;
+        }
+        assert_eq!(count, area.size.width * area.size.height);
+
+        Ok(())
+    }
+}
+
+impl OriginDimensions for ContiguousTarget {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+
+/// Checks that an image is drawn using `fill_contiguous` and matches the output of `decode_into`.
+#[track_caller]
+fn assert_draw_contiguous(data: &[u8]) {
+    let tga = Tga::<Rgb888>::from_slice(data).unwrap();
+
+    let mut expected = vec![Rgb888::BLACK; tga.size().width as usize * tga.size().height as usize];
+    tga.decode_into(&mut expected).unwrap();
+
+    let mut target = ContiguousTarget::new(tga.size());
+    Image::new(&tga, Point::zero()).draw(&mut target).unwrap();
+
+    assert_eq!(target.pixels, expected);
+}
+
+#[test]
+fn uncompressed() {
+    assert_draw_contiguous(include_bytes!("logo_type2_24bpp_tr.tga"));
+    assert_draw_contiguous(include_bytes!("logo_type2_24bpp_br.tga"));
+}
+
+#[test]
+fn rle() {
+    assert_draw_contiguous(include_bytes!("type10_24bpp_scan_lines_tr.tga"));
+    assert_draw_contiguous(include_bytes!("type10_24bpp_scan_lines_br.tga"));
+    assert_draw_contiguous(include_bytes!("type9_24bpp_scan_lines_tr.tga"));
+    assert_draw_contiguous(include_bytes!("type9_24bpp_scan_lines_br.tga"));
+}
+
+#[test]
+fn sub_image() {
+    let tga = Tga::<Rgb888>::from_slice(include_bytes!("logo_type2_24bpp_br.tga")).unwrap();
+    let area = Rectangle::new(Point::new(30, 40), Size::new(150, 100));
+
+    let mut expected = vec![Rgb888::BLACK; tga.size().width as usize * tga.size().height as usize];
+    tga.decode_into(&mut expected).unwrap();
+
+    let mut target = ContiguousTarget::new(area.size);
+    Image::new(&tga.sub_image(&area), Point::zero())
+        .draw(&mut target)
+        .unwrap();
+
+    for (p, color) in area.points().zip(target.pixels) {
+        assert_eq!(
+            color,
+            expected[p.y as usize * tga.size().width as usize + p.x as usize],
+            "{p:?}"
+        );
+    }
+}
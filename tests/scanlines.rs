@@ -0,0 +1,98 @@
+use embedded_graphics::{pixelcolor::Rgb888, prelude::*};
+use tinytga::{ParseError, RawTga, Tga};
+
+/// Checks that the scanlines match the output of `decode_into`.
+#[track_caller]
+fn assert_scanlines(data: &[u8]) {
+    let tga = Tga::<Rgb888>::from_slice(data).unwrap();
+    let raw = tga.as_raw();
+    let width = tga.size().width as usize;
+    let pixel_count = width * tga.size().height as usize;
+
+    let mut expected = vec![Rgb888::BLACK; pixel_count];
+    tga.decode_into(&mut expected).unwrap();
+
+    let mut scanlines = tga.scanlines();
+    let mut row = vec![Rgb888::BLACK; width];
+    let mut rows = Vec::new();
+    while let Some(y) = scanlines.next_row(&mut row).unwrap() {
+        assert_eq!(y as usize, rows.len());
+        assert_eq!(row, &expected[y as usize * width..][..width], "row {y}");
+        rows.push(y);
+    }
+    assert_eq!(rows.len(), tga.size().height as usize);
+    assert_eq!(scanlines.next_row(&mut row), Ok(None));
+
+    let mut expected = vec![0; pixel_count];
+    raw.decode_into(&mut expected).unwrap();
+
+    let mut scanlines = raw.scanlines();
+    let mut row = vec![0; width];
+    for y in 0..tga.size().height {
+        assert_eq!(scanlines.next_row(&mut row), Ok(Some(y)));
+        assert_eq!(row, &expected[y as usize * width..][..width], "row {y}");
+    }
+    assert_eq!(scanlines.next_row(&mut row), Ok(None));
+}
+
+#[test]
+fn uncompressed() {
+    assert_scanlines(include_bytes!("logo_type2_24bpp_tl.tga"));
+    assert_scanlines(include_bytes!("logo_type2_24bpp_bl.tga"));
+    assert_scanlines(include_bytes!("logo_type2_24bpp_tr.tga"));
+    assert_scanlines(include_bytes!("logo_type2_24bpp_br.tga"));
+    assert_scanlines(include_bytes!("type1_24bpp_idx16_bl.tga"));
+    assert_scanlines(include_bytes!("type3_bl.tga"));
+}
+
+#[test]
+fn rle() {
+    assert_scanlines(include_bytes!("type9_24bpp_tl.tga"));
+    assert_scanlines(include_bytes!("type9_24bpp_bl.tga"));
+    assert_scanlines(include_bytes!("type10_32bpp_bl.tga"));
+    assert_scanlines(include_bytes!("type11_tl.tga"));
+    assert_scanlines(include_bytes!("logo_type10_24bpp_tl.tga"));
+    assert_scanlines(include_bytes!("logo_type10_16bpp_bl.tga"));
+}
+
+#[test]
+fn rle_with_scan_line_table() {
+    assert_scanlines(include_bytes!("type10_24bpp_scan_lines_tl.tga"));
+    assert_scanlines(include_bytes!("type10_24bpp_scan_lines_bl.tga"));
+    assert_scanlines(include_bytes!("type10_24bpp_scan_lines_tr.tga"));
+    assert_scanlines(include_bytes!("type10_24bpp_scan_lines_br.tga"));
+}
+
+#[test]
+fn truncated_image_data() {
+    let raw = RawTga::from_slice(include_bytes!("error_truncated_image_data.tga")).unwrap();
+
+    let mut expected = vec![0; raw.size().width as usize * raw.size().height as usize];
+    raw.decode_into(&mut expected).unwrap();
+
+    let mut scanlines = raw.scanlines();
+    let mut row = vec![u32::MAX; raw.size().width as usize];
+    assert_eq!(scanlines.next_row(&mut row), Ok(Some(0)));
+    assert_eq!(row, &expected[0..row.len()]);
+}
+
+#[test]
+fn mismatched_length() {
+    let tga = Tga::<Rgb888>::from_slice(include_bytes!("type2_24bpp_tl.tga")).unwrap();
+
+    assert_eq!(
+        tga.scanlines().next_row(&mut [Rgb888::BLACK; 10]),
+        Err(ParseError::MismatchedLength {
+            expected: 9,
+            actual: 10
+        })
+    );
+
+    assert_eq!(
+        tga.as_raw().scanlines().next_row(&mut [0; 8]),
+        Err(ParseError::MismatchedLength {
+            expected: 9,
+            actual: 8
+        })
+    );
+}
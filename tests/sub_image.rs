@@ -0,0 +1,239 @@
+use embedded_graphics::{
+    image::{Image, ImageDrawableExt},
+    mock_display::MockDisplay,
+    pixelcolor::Rgb888,
+    prelude::*,
+    primitives::Rectangle,
+};
+use tinytga::{ImageOrigin, RawTga, Tga};
+
+const COLOR_PATTERN: &[&str] = &[
+    "WKRGBYMCW",
+    "KKRGBYMCW",
+    "WKRGBYMCW",
+    "KKKKKKKKK",
+    "WKWCMYBGR",
+];
+
+/// Areas used to test `draw_sub_image` with the 9x5 test images.
+const AREAS: &[Rectangle] = &[
+    Rectangle::new(Point::new(0, 0), Size::new(9, 5)),
+    Rectangle::new(Point::new(2, 1), Size::new(3, 2)),
+    Rectangle::new(Point::new(8, 4), Size::new(1, 1)),
+    Rectangle::new(Point::new(-2, -1), Size::new(5, 3)),
+    Rectangle::new(Point::new(6, 3), Size::new(10, 10)),
+    Rectangle::new(Point::new(20, 20), Size::new(3, 3)),
+];
+
+/// Checks that `draw_sub_image` draws the same pixels as a clipped version of `draw`.
+#[track_caller]
+fn assert_sub_image(tga: &Tga<Rgb888>, area: &Rectangle) {
+    // `sub_image` clips the area to the image bounding box.
+    let area = &area.intersection(&tga.bounding_box());
+
+    let mut full = MockDisplay::new();
+    full.set_allow_out_of_bounds_drawing(true);
+    Image::new(tga, Point::zero()).draw(&mut full).unwrap();
+
+    let mut expected = MockDisplay::new();
+    for p in area.points() {
+        expected.set_pixel(p - area.top_left, full.get_pixel(p));
+    }
+
+    let mut display = MockDisplay::new();
+    Image::new(&tga.sub_image(area), Point::zero())
+        .draw(&mut display)
+        .unwrap();
+
+    display.assert_eq(&expected);
+}
+
+fn test_scan_lines(data: &[u8]) {
+    let tga = Tga::<Rgb888>::from_slice(data).unwrap();
+    assert!(tga.as_raw().scan_line_table().is_some());
+
+    let mut display = MockDisplay::new();
+    Image::new(&tga, Point::zero()).draw(&mut display).unwrap();
+    display.assert_pattern(COLOR_PATTERN);
+
+    for area in AREAS {
+        assert_sub_image(&tga, area);
+    }
+}
+
+#[test]
+fn type10_scan_lines() {
+    test_scan_lines(include_bytes!("type10_24bpp_scan_lines_tl.tga"));
+    test_scan_lines(include_bytes!("type10_24bpp_scan_lines_bl.tga"));
+    test_scan_lines(include_bytes!("type10_24bpp_scan_lines_tr.tga"));
+    test_scan_lines(include_bytes!("type10_24bpp_scan_lines_br.tga"));
+}
+
+#[test]
+fn type9_scan_lines() {
+    test_scan_lines(include_bytes!("type9_24bpp_scan_lines_tl.tga"));
+    test_scan_lines(include_bytes!("type9_24bpp_scan_lines_bl.tga"));
+    test_scan_lines(include_bytes!("type9_24bpp_scan_lines_tr.tga"));
+    test_scan_lines(include_bytes!("type9_24bpp_scan_lines_br.tga"));
+}
+
+#[test]
+fn without_scan_lines() {
+    for data in [
+        &include_bytes!("type1_24bpp_tl.tga")[..],
+        include_bytes!("type1_24bpp_bl.tga"),
+        include_bytes!("type2_24bpp_tl.tga"),
+        include_bytes!("type2_24bpp_bl.tga"),
+        include_bytes!("type9_24bpp_tl.tga"),
+        include_bytes!("type9_24bpp_bl.tga"),
+        include_bytes!("type10_24bpp_tl.tga"),
+        include_bytes!("type10_24bpp_bl.tga"),
+    ] {
+        let tga = Tga::<Rgb888>::from_slice(data).unwrap();
+        assert_eq!(tga.as_raw().scan_line_table(), None);
+
+        for area in AREAS {
+            assert_sub_image(&tga, area);
+        }
+    }
+}
+
+#[test]
+fn right_origin() {
+    let area = Rectangle::new(Point::new(10, 20), Size::new(40, 30));
+
+    for data in [
+        &include_bytes!("logo_type2_24bpp_tr.tga")[..],
+        include_bytes!("logo_type2_24bpp_br.tga"),
+    ] {
+        let tga = Tga::<Rgb888>::from_slice(data).unwrap();
+        assert_sub_image(&tga, &area);
+    }
+}
+
+#[test]
+fn large_images() {
+    let area = Rectangle::new(Point::new(10, 20), Size::new(40, 30));
+
+    for data in [
+        &include_bytes!("logo_type1_24bpp_tl.tga")[..],
+        include_bytes!("logo_type2_16bpp_bl.tga"),
+        include_bytes!("logo_type9_16bpp_bl.tga"),
+        include_bytes!("logo_type10_24bpp_tl.tga"),
+        include_bytes!("logo_type11_bl.tga"),
+    ] {
+        let tga = Tga::<Rgb888>::from_slice(data).unwrap();
+        assert_sub_image(&tga, &area);
+    }
+}
+
+#[test]
+fn scan_line_table() {
+    let tga = RawTga::from_slice(include_bytes!("type10_24bpp_scan_lines_bl.tga")).unwrap();
+
+    let table = tga.scan_line_table().unwrap();
+    assert_eq!(table.len(), 5);
+    assert!(!table.is_empty());
+
+    // The first row in the image data starts directly after the header.
+    assert_eq!(table.get(0), Some(18));
+    assert_eq!(table.get(5), None);
+}
+
+/// Overwrites the image data before the last row in the file with a single run packet.
+///
+/// The packet contains more pixels than the entire image, which makes it impossible to decode
+/// the last row without using the scan line table.
+fn corrupt_rows(data: &[u8]) -> Vec<u8> {
+    let table = RawTga::from_slice(data).unwrap().scan_line_table().unwrap();
+    let start = table.get(0).unwrap();
+    let end = table.get(table.len() - 1).unwrap();
+
+    let mut data = data.to_vec();
+    data[start..end].fill(0xFF);
+
+    data
+}
+
+#[test]
+fn scan_line_table_is_used() {
+    for data in [
+        &include_bytes!("type10_24bpp_scan_lines_tl.tga")[..],
+        include_bytes!("type10_24bpp_scan_lines_bl.tga"),
+        include_bytes!("type9_24bpp_scan_lines_tr.tga"),
+        include_bytes!("type9_24bpp_scan_lines_br.tga"),
+    ] {
+        let tga = Tga::<Rgb888>::from_slice(data).unwrap();
+        let corrupted = corrupt_rows(data);
+        let corrupted = Tga::<Rgb888>::from_slice(&corrupted).unwrap();
+
+        // The last row in the file is the top row for images with a bottom origin.
+        let y = match tga.as_raw().image_origin() {
+            ImageOrigin::BottomLeft | ImageOrigin::BottomRight => 0,
+            ImageOrigin::TopLeft | ImageOrigin::TopRight => tga.size().height - 1,
+        };
+
+        // Decoding the image sequentially returns the corrupted data.
+        let row_pixels = |tga: &Tga<Rgb888>| -> Vec<_> {
+            tga.as_raw()
+                .pixels()
+                .filter(|p| p.position.y == y as i32)
+                .collect()
+        };
+        assert_ne!(row_pixels(&corrupted), row_pixels(&tga));
+
+        let expected: Vec<_> = tga.as_raw().row(y).unwrap().collect();
+        let row: Vec<_> = corrupted.as_raw().row(y).unwrap().collect();
+        assert_eq!(row, expected);
+
+        let area = Rectangle::new(Point::new(0, y as i32), Size::new(tga.size().width, 1));
+
+        let mut expected = MockDisplay::new();
+        Image::new(&tga.sub_image(&area), Point::zero())
+            .draw(&mut expected)
+            .unwrap();
+
+        let mut display = MockDisplay::new();
+        Image::new(&corrupted.sub_image(&area), Point::zero())
+            .draw(&mut display)
+            .unwrap();
+
+        display.assert_eq(&expected);
+    }
+}
+
+fn test_rows(data: &[u8]) {
+    let tga = RawTga::from_slice(data).unwrap();
+    let width = tga.size().width as usize;
+
+    // Pixels in each row are returned in the same order as in the file, which is right to left
+    // for right origin images.
+    let mut rows: Vec<Vec<u32>> = vec![Vec::new(); tga.size().height as usize];
+    for pixel in tga.pixels() {
+        rows[pixel.position.y as usize].push(pixel.color);
+    }
+    for (y, expected) in rows.iter().enumerate() {
+        let row: Vec<_> = tga.row(y as u32).unwrap().collect();
+        assert_eq!(row.len(), width);
+        assert_eq!(&row, expected, "row {}", y);
+
+        // Skipped values aren't decoded.
+        let skipped: Vec<_> = tga.row(y as u32).unwrap().skip(3).collect();
+        assert_eq!(skipped, &expected[3..], "row {}", y);
+        assert_eq!(tga.row(y as u32).unwrap().nth(width), None);
+    }
+
+    assert!(tga.row(tga.size().height).is_none());
+}
+
+#[test]
+fn rows() {
+    test_rows(include_bytes!("type10_24bpp_scan_lines_tl.tga"));
+    test_rows(include_bytes!("type10_24bpp_scan_lines_bl.tga"));
+    test_rows(include_bytes!("type9_24bpp_scan_lines_br.tga"));
+    test_rows(include_bytes!("type2_24bpp_tl.tga"));
+    test_rows(include_bytes!("type2_24bpp_bl.tga"));
+    test_rows(include_bytes!("type10_24bpp_tl.tga"));
+    test_rows(include_bytes!("type10_24bpp_bl.tga"));
+    test_rows(include_bytes!("logo_type2_24bpp_tr.tga"));
+}
@@ -107,6 +107,100 @@ fn type1_24bpp_tl() {
     assert_eq!(tga.image_data_bpp(), Bpp::Bits8);
 }
 
+#[test]
+fn type1_24bpp_idx16_bl() {
+    let tga = RawTga::from_slice(include_bytes!("../tests/type1_24bpp_idx16_bl.tga")).unwrap();
+
+    assert_eq!(
+        tga.header(),
+        TgaHeader {
+            has_color_map: true,
+            data_type: DataType::ColorMapped,
+            color_map_start: 0,
+            color_map_len: 300,
+            color_map_depth: Some(Bpp::Bits24),
+            pixel_depth: Bpp::Bits16,
+            ..HEADER_DEFAULT
+        }
+    );
+    assert_eq!(tga.developer_directory(), None);
+    assert_eq!(tga.extension_area(), None);
+
+    assert_eq!(tga.color_bpp(), Bpp::Bits24);
+    assert_eq!(tga.image_data_bpp(), Bpp::Bits16);
+}
+
+#[test]
+fn type1_24bpp_idx16_tl() {
+    let tga = RawTga::from_slice(include_bytes!("../tests/type1_24bpp_idx16_tl.tga")).unwrap();
+
+    assert_eq!(
+        tga.header(),
+        TgaHeader {
+            has_color_map: true,
+            data_type: DataType::ColorMapped,
+            color_map_start: 0,
+            color_map_len: 300,
+            color_map_depth: Some(Bpp::Bits24),
+            pixel_depth: Bpp::Bits16,
+            image_origin: ImageOrigin::TopLeft,
+            ..HEADER_DEFAULT
+        }
+    );
+    assert_eq!(tga.developer_directory(), None);
+    assert_eq!(tga.extension_area(), None);
+
+    assert_eq!(tga.color_bpp(), Bpp::Bits24);
+    assert_eq!(tga.image_data_bpp(), Bpp::Bits16);
+}
+
+#[test]
+fn type1_32bpp_bl() {
+    let tga = RawTga::from_slice(include_bytes!("../tests/type1_32bpp_bl.tga")).unwrap();
+
+    assert_eq!(
+        tga.header(),
+        TgaHeader {
+            has_color_map: true,
+            data_type: DataType::ColorMapped,
+            color_map_start: 0,
+            color_map_len: 8,
+            color_map_depth: Some(Bpp::Bits32),
+            alpha_channel_depth: 8,
+            ..HEADER_DEFAULT
+        }
+    );
+    assert_eq!(tga.developer_directory(), None);
+    assert_eq!(tga.extension_area(), None);
+
+    assert_eq!(tga.color_bpp(), Bpp::Bits32);
+    assert_eq!(tga.image_data_bpp(), Bpp::Bits8);
+}
+
+#[test]
+fn type1_32bpp_tl() {
+    let tga = RawTga::from_slice(include_bytes!("../tests/type1_32bpp_tl.tga")).unwrap();
+
+    assert_eq!(
+        tga.header(),
+        TgaHeader {
+            has_color_map: true,
+            data_type: DataType::ColorMapped,
+            color_map_start: 0,
+            color_map_len: 8,
+            color_map_depth: Some(Bpp::Bits32),
+            image_origin: ImageOrigin::TopLeft,
+            alpha_channel_depth: 8,
+            ..HEADER_DEFAULT
+        }
+    );
+    assert_eq!(tga.developer_directory(), None);
+    assert_eq!(tga.extension_area(), None);
+
+    assert_eq!(tga.color_bpp(), Bpp::Bits32);
+    assert_eq!(tga.image_data_bpp(), Bpp::Bits8);
+}
+
 #[test]
 fn type2_16bpp_bl() {
     let tga = RawTga::from_slice(include_bytes!("../tests/type2_16bpp_bl.tga")).unwrap();
@@ -185,6 +279,51 @@ fn type2_24bpp_tl() {
     assert_eq!(tga.image_data_bpp(), Bpp::Bits24);
 }
 
+#[test]
+fn type2_32bpp_bl() {
+    let tga = RawTga::from_slice(include_bytes!("../tests/type2_32bpp_bl.tga")).unwrap();
+
+    assert_eq!(
+        tga.header(),
+        TgaHeader {
+            data_type: DataType::TrueColor,
+            pixel_depth: Bpp::Bits32,
+            alpha_channel_depth: 8,
+            ..HEADER_DEFAULT
+        }
+    );
+    assert_eq!(tga.developer_directory(), None);
+    assert_eq!(tga.extension_area(), None);
+
+    assert_eq!(tga.color_bpp(), Bpp::Bits32);
+    assert_eq!(tga.image_data_bpp(), Bpp::Bits32);
+}
+
+#[test]
+fn type2_32bpp_tl() {
+    let tga = RawTga::from_slice(include_bytes!("../tests/type2_32bpp_tl.tga")).unwrap();
+
+    assert_eq!(
+        tga.header(),
+        TgaHeader {
+            data_type: DataType::TrueColor,
+            pixel_depth: Bpp::Bits32,
+            image_origin: ImageOrigin::TopLeft,
+            alpha_channel_depth: 8,
+            ..HEADER_DEFAULT
+        }
+    );
+    assert_eq!(tga.developer_directory(), None);
+    assert_eq!(tga.extension_area(), None);
+
+    assert_eq!(tga.color_bpp(), Bpp::Bits32);
+    assert_eq!(tga.image_data_bpp(), Bpp::Bits32);
+
+    // The alpha channel is preserved in the upper 8 bits of the raw pixel values.
+    let colors: Vec<_> = tga.pixels().take(3).map(|p| p.color).collect();
+    assert_eq!(colors, [0xFFFFFFFF, 0x80000000, 0x00FF0000]);
+}
+
 #[test]
 fn type3_bl() {
     let tga = RawTga::from_slice(include_bytes!("../tests/type3_bl.tga")).unwrap();
@@ -316,6 +455,104 @@ fn type9_24bpp_tl() {
     assert_eq!(tga.image_data_bpp(), Bpp::Bits8);
 }
 
+#[test]
+fn type9_24bpp_idx16_bl() {
+    let tga = RawTga::from_slice(include_bytes!("../tests/type9_24bpp_idx16_bl.tga")).unwrap();
+
+    assert_eq!(
+        tga.header(),
+        TgaHeader {
+            has_color_map: true,
+            data_type: DataType::ColorMapped,
+            compression: Compression::Rle,
+            color_map_start: 0,
+            color_map_len: 300,
+            color_map_depth: Some(Bpp::Bits24),
+            pixel_depth: Bpp::Bits16,
+            ..HEADER_DEFAULT
+        }
+    );
+    assert_eq!(tga.developer_directory(), None);
+    assert_eq!(tga.extension_area(), None);
+
+    assert_eq!(tga.color_bpp(), Bpp::Bits24);
+    assert_eq!(tga.image_data_bpp(), Bpp::Bits16);
+}
+
+#[test]
+fn type9_24bpp_idx16_tl() {
+    let tga = RawTga::from_slice(include_bytes!("../tests/type9_24bpp_idx16_tl.tga")).unwrap();
+
+    assert_eq!(
+        tga.header(),
+        TgaHeader {
+            has_color_map: true,
+            data_type: DataType::ColorMapped,
+            compression: Compression::Rle,
+            color_map_start: 0,
+            color_map_len: 300,
+            color_map_depth: Some(Bpp::Bits24),
+            pixel_depth: Bpp::Bits16,
+            image_origin: ImageOrigin::TopLeft,
+            ..HEADER_DEFAULT
+        }
+    );
+    assert_eq!(tga.developer_directory(), None);
+    assert_eq!(tga.extension_area(), None);
+
+    assert_eq!(tga.color_bpp(), Bpp::Bits24);
+    assert_eq!(tga.image_data_bpp(), Bpp::Bits16);
+}
+
+#[test]
+fn type9_32bpp_bl() {
+    let tga = RawTga::from_slice(include_bytes!("../tests/type9_32bpp_bl.tga")).unwrap();
+
+    assert_eq!(
+        tga.header(),
+        TgaHeader {
+            has_color_map: true,
+            data_type: DataType::ColorMapped,
+            compression: Compression::Rle,
+            color_map_start: 0,
+            color_map_len: 8,
+            color_map_depth: Some(Bpp::Bits32),
+            alpha_channel_depth: 8,
+            ..HEADER_DEFAULT
+        }
+    );
+    assert_eq!(tga.developer_directory(), None);
+    assert_eq!(tga.extension_area(), None);
+
+    assert_eq!(tga.color_bpp(), Bpp::Bits32);
+    assert_eq!(tga.image_data_bpp(), Bpp::Bits8);
+}
+
+#[test]
+fn type9_32bpp_tl() {
+    let tga = RawTga::from_slice(include_bytes!("../tests/type9_32bpp_tl.tga")).unwrap();
+
+    assert_eq!(
+        tga.header(),
+        TgaHeader {
+            has_color_map: true,
+            data_type: DataType::ColorMapped,
+            compression: Compression::Rle,
+            color_map_start: 0,
+            color_map_len: 8,
+            color_map_depth: Some(Bpp::Bits32),
+            image_origin: ImageOrigin::TopLeft,
+            alpha_channel_depth: 8,
+            ..HEADER_DEFAULT
+        }
+    );
+    assert_eq!(tga.developer_directory(), None);
+    assert_eq!(tga.extension_area(), None);
+
+    assert_eq!(tga.color_bpp(), Bpp::Bits32);
+    assert_eq!(tga.image_data_bpp(), Bpp::Bits8);
+}
+
 #[test]
 fn type10_16bpp_bl() {
     let tga = RawTga::from_slice(include_bytes!("../tests/type10_16bpp_bl.tga")).unwrap();
@@ -398,6 +635,49 @@ fn type10_24bpp_tl() {
     assert_eq!(tga.image_data_bpp(), Bpp::Bits24);
 }
 
+#[test]
+fn type10_32bpp_bl() {
+    let tga = RawTga::from_slice(include_bytes!("../tests/type10_32bpp_bl.tga")).unwrap();
+
+    assert_eq!(
+        tga.header(),
+        TgaHeader {
+            data_type: DataType::TrueColor,
+            compression: Compression::Rle,
+            pixel_depth: Bpp::Bits32,
+            alpha_channel_depth: 8,
+            ..HEADER_DEFAULT
+        }
+    );
+    assert_eq!(tga.developer_directory(), None);
+    assert_eq!(tga.extension_area(), None);
+
+    assert_eq!(tga.color_bpp(), Bpp::Bits32);
+    assert_eq!(tga.image_data_bpp(), Bpp::Bits32);
+}
+
+#[test]
+fn type10_32bpp_tl() {
+    let tga = RawTga::from_slice(include_bytes!("../tests/type10_32bpp_tl.tga")).unwrap();
+
+    assert_eq!(
+        tga.header(),
+        TgaHeader {
+            data_type: DataType::TrueColor,
+            compression: Compression::Rle,
+            pixel_depth: Bpp::Bits32,
+            image_origin: ImageOrigin::TopLeft,
+            alpha_channel_depth: 8,
+            ..HEADER_DEFAULT
+        }
+    );
+    assert_eq!(tga.developer_directory(), None);
+    assert_eq!(tga.extension_area(), None);
+
+    assert_eq!(tga.color_bpp(), Bpp::Bits32);
+    assert_eq!(tga.image_data_bpp(), Bpp::Bits32);
+}
+
 #[test]
 fn type11_bl() {
     let tga = RawTga::from_slice(include_bytes!("../tests/type11_bl.tga")).unwrap();
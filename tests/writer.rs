@@ -0,0 +1,112 @@
+use embedded_graphics::{
+    image::{GetPixel, Image},
+    mock_display::MockDisplay,
+    pixelcolor::{Gray8, Rgb555, Rgb888},
+    prelude::*,
+    primitives::{PrimitiveStyle, Rectangle},
+};
+use tinytga::{Compression, EncodeError, Tga, TgaWriter};
+
+#[test]
+fn draw_image() {
+    let tga = Tga::<Rgb888>::from_slice(include_bytes!("type2_24bpp_tl.tga")).unwrap();
+
+    let mut buffer = [Rgb888::BLACK; 9 * 5];
+    let mut writer = TgaWriter::new(&mut buffer, Size::new(9, 5)).unwrap();
+    Image::new(&tga, Point::zero()).draw(&mut writer).unwrap();
+
+    let mut data = Vec::new();
+    writer.encode(&mut data).unwrap();
+    assert_eq!(data, include_bytes!("type2_24bpp_tl.tga"));
+}
+
+#[test]
+fn draw_rle_image() {
+    let tga = Tga::<Gray8>::from_slice(include_bytes!("type11_bl.tga")).unwrap();
+
+    let mut buffer = [Gray8::BLACK; 9 * 5];
+    let mut writer = TgaWriter::new(&mut buffer, Size::new(9, 5)).unwrap();
+    Image::new(&tga, Point::zero()).draw(&mut writer).unwrap();
+
+    let mut data = Vec::new();
+    writer
+        .encoder()
+        .with_compression(Compression::Rle)
+        .encode(writer.colors(), &mut data)
+        .unwrap();
+
+    let mut expected = MockDisplay::new();
+    Image::new(&tga, Point::zero()).draw(&mut expected).unwrap();
+
+    let decoded = Tga::<Gray8>::from_slice(&data).unwrap();
+    let mut display = MockDisplay::new();
+    Image::new(&decoded, Point::zero())
+        .draw(&mut display)
+        .unwrap();
+
+    display.assert_eq(&expected);
+}
+
+#[test]
+fn snapshot() {
+    let mut buffer = [Rgb555::BLACK; 9 * 5];
+    let mut writer = TgaWriter::new(&mut buffer, Size::new(9, 5)).unwrap();
+
+    Rectangle::new(Point::new(1, 1), Size::new(3, 2))
+        .into_styled(PrimitiveStyle::with_fill(Rgb555::RED))
+        .draw(&mut writer)
+        .unwrap();
+    Pixel(Point::new(8, 4), Rgb555::GREEN)
+        .draw(&mut writer)
+        .unwrap();
+
+    let mut data = Vec::new();
+    writer.encode(&mut data).unwrap();
+
+    let tga = Tga::<Rgb555>::from_slice(&data).unwrap();
+    let mut display = MockDisplay::new();
+    Image::new(&tga, Point::zero()).draw(&mut display).unwrap();
+    display.assert_pattern(&[
+        "KKKKKKKKK", //
+        "KRRRKKKKK", //
+        "KRRRKKKKK", //
+        "KKKKKKKKK", //
+        "KKKKKKKKG", //
+    ]);
+}
+
+#[test]
+fn outside_of_image() {
+    let mut buffer = [Rgb888::BLACK; 4 * 3];
+    let mut writer = TgaWriter::new(&mut buffer, Size::new(4, 3)).unwrap();
+
+    Rectangle::new(Point::new(-2, 1), Size::new(10, 10))
+        .into_styled(PrimitiveStyle::with_fill(Rgb888::BLUE))
+        .draw(&mut writer)
+        .unwrap();
+    Pixel(Point::new(4, 0), Rgb888::RED)
+        .draw(&mut writer)
+        .unwrap();
+    Pixel(Point::new(-1, 0), Rgb888::RED)
+        .draw(&mut writer)
+        .unwrap();
+
+    assert_eq!(writer.pixel(Point::new(0, 0)), Some(Rgb888::BLACK));
+    assert_eq!(writer.pixel(Point::new(3, 0)), Some(Rgb888::BLACK));
+    assert_eq!(writer.pixel(Point::new(0, 1)), Some(Rgb888::BLUE));
+    assert_eq!(writer.pixel(Point::new(3, 2)), Some(Rgb888::BLUE));
+    assert_eq!(writer.pixel(Point::new(4, 0)), None);
+}
+
+#[test]
+fn mismatched_buffer_length() {
+    let mut buffer = [Rgb888::BLACK; 10];
+
+    assert_eq!(
+        TgaWriter::new(&mut buffer, Size::new(4, 3)),
+        Err(EncodeError::MismatchedLength {
+            expected: 12,
+            actual: 10
+        })
+    );
+}